@@ -0,0 +1,89 @@
+// USE
+use crate::asteroid::AsteroidSize;
+use crate::events::{EventListener, GameEvent};
+use crate::ufo::UfoSize;
+
+/// seconds a new achievement stays on screen
+const SHOW_TIME: f32 = 3.;
+
+/// asteroids for the rock breaker achievement
+const ROCK_BREAKER_COUNT: u32 = 50;
+/// jumps for the frequent flyer achievement
+const FREQUENT_FLYER_COUNT: u32 = 5;
+/// level for the veteran achievement
+const VETERAN_LEVEL: u32 = 5;
+
+/// goals reached during a game, each one is announced once
+pub struct Achievements {
+    unlocked: Vec<&'static str>,
+    latest: Option<&'static str>,
+    show_time: f32,
+    asteroids: u32,
+    jumps: u32,
+}
+
+impl Achievements {
+    pub fn new() -> Self {
+        Self {
+            unlocked: vec![],
+            latest: None,
+            show_time: 0.,
+            asteroids: 0,
+            jumps: 0,
+        }
+    }
+
+    fn unlock(&mut self, name: &'static str) {
+        if !self.unlocked.contains(&name) {
+            self.unlocked.push(name);
+            self.latest = Some(name);
+            self.show_time = SHOW_TIME;
+        }
+    }
+
+    /// last achievement while it is still being shown
+    pub fn get_latest(&self) -> Option<&'static str> {
+        if self.show_time > 0. {
+            self.latest
+        } else {
+            None
+        }
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        self.show_time = (self.show_time - delta).max(0.);
+    }
+}
+
+impl EventListener for Achievements {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::AsteroidDestroyed { size, by_player: true, .. } => {
+                self.asteroids += 1;
+                self.unlock("FIRST ROCK");
+
+                if let AsteroidSize::SMALL = size {
+                    self.unlock("PEBBLE POPPER");
+                }
+                if self.asteroids >= ROCK_BREAKER_COUNT {
+                    self.unlock("ROCK BREAKER");
+                }
+            }
+            GameEvent::UfoDestroyed { size, by_player: true, .. } => {
+                self.unlock("UFO HUNTER");
+
+                if size == UfoSize::SMALL {
+                    self.unlock("SAUCER SNIPER");
+                }
+            }
+            GameEvent::Hyperspace { .. } => {
+                self.jumps += 1;
+                if self.jumps >= FREQUENT_FLYER_COUNT {
+                    self.unlock("FREQUENT FLYER");
+                }
+            }
+            GameEvent::WaveCleared { level } if level >= VETERAN_LEVEL => self.unlock("VETERAN"),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// USE
+use sfml::system::*;
+//
+use crate::globals::{random_number, v2_dot, GameRng};
+
+/// furthest off a shot goes at zero accuracy, in radians
+const MAX_AIM_ERROR: f32 = 0.5;
+
+/// seconds until a bullet fired now at bullet_speed can meet a target
+/// at offset moving at velocity, None if it can never catch it
+fn intercept_time(offset: Vector2f, velocity: Vector2f, bullet_speed: f32) -> Option<f32> {
+    // |offset + velocity * t| = bullet_speed * t
+    let a = v2_dot(velocity, velocity) - bullet_speed * bullet_speed;
+    let b = 2. * v2_dot(offset, velocity);
+    let c = v2_dot(offset, offset);
+
+    // same speed as the bullet, only one answer
+    if a.abs() <= f32::EPSILON {
+        if b.abs() <= f32::EPSILON {
+            return None;
+        }
+        let t = -c / b;
+        return if t > 0. { Some(t) } else { None };
+    }
+
+    let discriminant = b * b - 4. * a * c;
+    if discriminant < 0. {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let t1 = (-b - root) / (2. * a);
+    let t2 = (-b + root) / (2. * a);
+
+    // soonest time still ahead
+    match (t1 > 0., t2 > 0.) {
+        (true, true) => Some(t1.min(t2)),
+        (true, false) => Some(t1),
+        (false, true) => Some(t2),
+        (false, false) => None,
+    }
+}
+
+/// angle to fire at so a bullet meets a moving target. bullets dont wrap
+/// so it never aims across the screen edges. falls back to aiming straight
+/// at the target when it cant be caught
+pub fn lead_angle(shooter: Vector2f, target: Vector2f, target_velocity: Vector2f, bullet_speed: f32) -> f32 {
+    let offset = target - shooter;
+
+    let aim_point = match intercept_time(offset, target_velocity, bullet_speed) {
+        Some(t) => offset + target_velocity * t,
+        None => offset,
+    };
+
+    aim_point.y.atan2(aim_point.x)
+}
+
+/// lead angle missed by a random amount, accuracy 1 is a perfect shot
+/// and 0 can be off by MAX_AIM_ERROR either way
+pub fn aim(
+    rng: &mut GameRng,
+    shooter: Vector2f,
+    target: Vector2f,
+    target_velocity: Vector2f,
+    bullet_speed: f32,
+    accuracy: f32,
+) -> f32 {
+    let angle = lead_angle(shooter, target, target_velocity, bullet_speed);
+    let error = MAX_AIM_ERROR * (1. - accuracy.clamp(0., 1.));
+    if error <= 0. {
+        return angle;
+    }
+    angle + random_number(rng, -error, error)
+}
@@ -4,9 +4,14 @@ use sfml::{graphics::*, system::*};
 use std::f32::consts::PI;
 //
 use crate::baseobject::BaseObject;
-use crate::globals::{SCREEN_WIDTH, SCREEN_HEIGHT};
 use crate::boxarea::BoxArea;
+use crate::collision::Collider;
+use crate::convex::ConvexParts;
+use crate::globals::{random_bool, random_number, GameRng};
+use crate::interpolate::lerp_points;
 use crate::isactive::IsActive;
+use crate::layer::{self, LayerBits};
+use crate::wrap::{draw_wrapped, wrap_position};
 
 /// asteroid types
 #[derive(Copy, Clone)]
@@ -18,15 +23,112 @@ pub enum AsteroidSize {
     NONE,
 }
 
+/// how an asteroid breaks up when it is destroyed
+pub struct SplitRule {
+    pub child_size: AsteroidSize,
+    pub count: usize,
+    /// share of the parents velocity each child keeps
+    pub inherit: f32,
+    /// speed the children fan out sideways from the impact
+    pub spread_speed: f32,
+    /// speed the children are knocked along the impact
+    pub push_speed: f32,
+}
+
+impl AsteroidSize {
+    /// what this size breaks into, None when it just vanishes
+    pub fn split_rule(&self) -> Option<SplitRule> {
+        match self {
+            AsteroidSize::LARGE => Some(SplitRule {
+                child_size: AsteroidSize::MEDIUM,
+                count: 2,
+                inherit: 1.,
+                spread_speed: 70.,
+                push_speed: 30.,
+            }),
+            AsteroidSize::MEDIUM => Some(SplitRule {
+                child_size: AsteroidSize::SMALL,
+                count: 2,
+                inherit: 1.,
+                spread_speed: 90.,
+                push_speed: 40.,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// how an asteroids outline is generated
+pub struct OutlineParams {
+    /// corners around the outline
+    pub vertex_count: usize,
+    /// furthest a corner can be from the centre
+    pub radius: f32,
+    /// 0 is a regular polygon, 1 lets corners fall right in to the centre
+    pub roughness: f32,
+}
+
+impl OutlineParams {
+    pub fn for_size(size_type: AsteroidSize) -> Self {
+        match size_type {
+            AsteroidSize::SMALL => Self {
+                vertex_count: 8,
+                radius: 10.,
+                roughness: 0.4,
+            },
+            AsteroidSize::MEDIUM => Self {
+                vertex_count: 10,
+                radius: 20.,
+                roughness: 0.4,
+            },
+            AsteroidSize::LARGE => Self {
+                vertex_count: 12,
+                radius: 55.,
+                roughness: 0.35,
+            },
+            AsteroidSize::NONE => Self {
+                vertex_count: 3,
+                radius: 0.,
+                roughness: 0.,
+            },
+        }
+    }
+}
+
+/// jagged closed outline, corners go round the centre in order at random
+/// distances so the shape never crosses itself. the first point is repeated
+/// at the end for drawing as a LineStrip
+pub fn generate_outline(rng: &mut GameRng, params: &OutlineParams) -> Vec<Vertex> {
+    let count = params.vertex_count.max(3);
+    let step = PI * 2. / count as f32;
+
+    let mut points: Vec<Vertex> = (0..count)
+        .map(|x| {
+            // stay inside this corners slice of the circle
+            let angle = x as f32 * step + random_number(rng, -0.3, 0.3) * step;
+            let dist = match params.roughness > 0. {
+                true => params.radius * (1. - random_number(rng, 0., params.roughness)),
+                false => params.radius,
+            };
+            Vertex::with_pos((angle.cos() * dist, angle.sin() * dist))
+        })
+        .collect();
+
+    points.push(points[0]);
+    points
+}
+
 /// asteroid
 #[allow(dead_code)]
 pub struct Asteroid {
     base: BaseObject,
     asteroid_size: AsteroidSize,
     rotate_speed: f32,
-    points: [Vertex; 9],
-    transform_points: [Vertex; 9],
+    points: Vec<Vertex>,
+    transform_points: Vec<Vertex>,
+    prev_transform_points: Vec<Vertex>,
     tp: Vec<Vector2f>,
+    parts: ConvexParts,
     flip_color: bool,
     is_debug:bool,
     box_area: BoxArea,
@@ -51,57 +153,25 @@ impl Asteroid {
         ang: f32,
         acc: f32,
         rotate_speed: f32,
-        rotate_right: bool,
         size_type: AsteroidSize,
+        rng: &mut GameRng,
     ) -> Self {
-        let points = match size_type {
-            AsteroidSize::SMALL => [
-                Vertex::with_pos((0.0, -10.20)),
-                Vertex::with_pos((5.50, -4.50)),
-                Vertex::with_pos((8.0, 0.0)),
-                Vertex::with_pos((5.0, 3.50)),
-                Vertex::with_pos((0.0, 9.0)),
-                Vertex::with_pos((-3.80, 4.0)),
-                Vertex::with_pos((-4.45, 0.0)),
-                Vertex::with_pos((-2.80, -3.0)),
-                Vertex::with_pos((0.0, -10.27)),
-            ],
-            AsteroidSize::MEDIUM => [
-                Vertex::with_pos((0.0, -20.0)),
-                Vertex::with_pos((4.0, -6.0)),
-                Vertex::with_pos((4.5, 0.0)),
-                Vertex::with_pos((4.3, 3.9)),
-                Vertex::with_pos((0.0, 10.0)),
-                Vertex::with_pos((-8.2, 8.67)),
-                Vertex::with_pos((-10.0, 0.0)),
-                Vertex::with_pos((-6.4, -14.3)),
-                Vertex::with_pos((0.0, -20.0)),
-            ],
-            AsteroidSize::LARGE => [
-                Vertex::with_pos((0.0, -50.0)),
-                Vertex::with_pos((40.0, -40.0)),
-                Vertex::with_pos((60.0, 0.0)),
-                Vertex::with_pos((30.0, 35.0)),
-                Vertex::with_pos((0.0, 50.0)),
-                Vertex::with_pos((-40.5, 40.0)),
-                Vertex::with_pos((-55.0, 0.0)),
-                Vertex::with_pos((-35.0, -30.0)),
-                Vertex::with_pos((0.0, -50.0)),
-            ],
-            AsteroidSize::NONE => [Vertex::default(); 9],
-        };
+        let params = OutlineParams::for_size(size_type);
+        let points = generate_outline(rng, &params);
+        let count = points.len();
+        let parts = ConvexParts::from_vertices(&points);
 
         let dx = ang.cos() * acc;
         let dy = ang.sin() * acc;
 
-        let speed = match rotate_right {
+        let speed = match random_bool(rng) {
             true => rotate_speed,
             false => -rotate_speed,
         };
 
-        let ba = BoxArea::new(x, y, 110., 110.);
+        let ba = BoxArea::new(x, y, params.radius * 2., params.radius * 2.);
 
-        Self {
+        let mut asteroid = Self {
             base: BaseObject {
                 position: Vector2f::new(x, y),
                 velocity: Vector2f::new(dx, dy),
@@ -112,18 +182,29 @@ impl Asteroid {
             asteroid_size: size_type,
             rotate_speed: speed,
             points: points,
-            transform_points: [Vertex::default(); 9],
-            tp: vec![Vector2f::default(); 9],
+            transform_points: vec![Vertex::default(); count],
+            prev_transform_points: vec![Vertex::default(); count],
+            tp: vec![Vector2f::default(); count],
+            parts,
             flip_color: false,
             is_debug: false,
             box_area: ba,
-        }
+        };
+
+        asteroid.update_points();
+        asteroid.prev_transform_points = asteroid.transform_points.clone();
+        asteroid
     }
 
     pub fn get_box_area(&self)->&BoxArea{
         &self.box_area
     }
 
+    /// collision layer it sits on
+    pub fn get_layer(&self) -> LayerBits {
+        layer::HAZARD
+    }
+
     /// return current screen position
     pub fn get_position(&self) -> Vector2f {
         self.base.position
@@ -133,6 +214,43 @@ impl Asteroid {
         self.asteroid_size
     }
 
+    /// mass for bouncing, grows with size
+    pub fn get_mass(&self) -> f32 {
+        match self.asteroid_size {
+            AsteroidSize::SMALL => 1.,
+            AsteroidSize::MEDIUM => 2.,
+            AsteroidSize::LARGE => 3.,
+            AsteroidSize::NONE => 1.,
+        }
+    }
+
+    /// pixels per second
+    pub fn get_velocity(&self) -> Vector2f {
+        self.base.velocity * self.base.acceleration
+    }
+
+    /// set pixels per second
+    pub fn set_velocity(&mut self, velocity: Vector2f) {
+        self.base.velocity = velocity / self.base.acceleration;
+    }
+
+    /// radians per second, negative turns left
+    pub fn get_rotate_speed(&self) -> f32 {
+        self.rotate_speed
+    }
+
+    pub fn set_rotate_speed(&mut self, speed: f32) {
+        self.rotate_speed = speed;
+    }
+
+    /// nudge the asteroid, used to push overlapping asteroids apart
+    pub fn move_by(&mut self, offset: Vector2f) {
+        self.base.position = wrap_position(self.base.position + offset);
+        self.box_area.set_position(self.get_position());
+        self.box_area.update();
+        self.update_points();
+    }
+
     /// toggle color
     pub fn toggle_color(&mut self, value: bool) {
         self.flip_color = value;
@@ -143,6 +261,16 @@ impl Asteroid {
         &self.tp
     }
 
+    /// outline, pieces and box for collision tests
+    pub fn get_collider(&self) -> Collider<'_> {
+        Collider {
+            box_area: &self.box_area,
+            tp: &self.tp,
+            parts: &self.parts,
+            wraps_x: true,
+        }
+    }
+
     /// update transform points
     fn update_points(&mut self) {
         for (idx, p) in self.points.iter_mut().enumerate() {
@@ -166,41 +294,27 @@ impl Asteroid {
         }
     }
 
-    /// draw asteroids transform points to screen in LineStrip format
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    /// draw asteroids transform points to screen in LineStrip format,
+    /// blended between the last two steps by alpha
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         if self.is_active() {
             if self.is_debug {
                 self.box_area.draw(window);
             }
 
-            window.draw_primitives(
-                &self.transform_points,
+            draw_wrapped(
+                window,
+                &lerp_points(&self.prev_transform_points, &self.transform_points, alpha),
                 PrimitiveType::LineStrip,
-                RenderStates::default(),
+                &self.box_area,
             );
         }
     }
 
-    fn screen_wrap(&mut self, width: f32, height: f32, padding: f32) {
-        // screen wrap
-        let screen_edge = 0.;
-
-        if self.base.position.x > width + padding {
-            self.base.position.x = screen_edge - padding;
-        }
-        if self.base.position.x < screen_edge - padding {
-            self.base.position.x = width + padding;
-        }
-        if self.base.position.y > height + padding {
-            self.base.position.y = screen_edge - padding;
-        }
-        if self.base.position.y < screen_edge - padding {
-            self.base.position.y = height + padding;
-        }
-    }
-
     pub fn update(&mut self, delta: f32) {
         if self.is_active() {
+            self.prev_transform_points.clone_from(&self.transform_points);
+
             self.base.angle += self.rotate_speed * delta;
 
             if self.base.angle < 0.0 {
@@ -211,11 +325,11 @@ impl Asteroid {
             }
 
             self.base.position += self.base.velocity * self.base.acceleration * delta;
-            
+            self.base.position = wrap_position(self.base.position);
+
             self.box_area.set_position(self.get_position());
             self.box_area.update();
-            
-            self.screen_wrap(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32, 50.);
+
             self.update_points();
         }
     }
@@ -57,17 +57,19 @@ impl BoxArea {
         self.size
     }
 
+    #[allow(dead_code)]
     pub fn toggle_active(&mut self) {
         self.is_active = !self.is_active;
     }
 
+    #[allow(dead_code)]
     /// change color of box for collison indication
     pub fn toggle_color(&mut self, value: bool) {
         self.flip_color = value;
     }
 
     /// draw box to screen
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    pub fn draw(&self, window: &mut RenderWindow) {
         if self.is_active() {
             window.draw_primitives(
                 &self.transform_points,
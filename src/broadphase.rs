@@ -0,0 +1,134 @@
+// USE
+use sfml::system::*;
+//
+use crate::boxarea::BoxArea;
+use crate::layer::LayerBits;
+use crate::wrap::ghost_offsets;
+
+/// which object a collider belongs to, index into the worlds lists
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColliderId {
+    SHIP,
+    UFO,
+    BULLET(usize),
+    ASTEROID(usize),
+}
+
+impl ColliderId {
+    /// index into its list, 0 for the ship and ufo
+    pub fn index(&self) -> usize {
+        match *self {
+            ColliderId::SHIP | ColliderId::UFO => 0,
+            ColliderId::BULLET(i) | ColliderId::ASTEROID(i) => i,
+        }
+    }
+}
+
+/// a collider in the grid, the layer it is on and the layers it hits.
+/// ordered by layer first so pairs come out lower layer first
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Body {
+    pub layer: LayerBits,
+    pub mask: LayerBits,
+    pub id: ColliderId,
+    /// false for things that leave by the sides instead of wrapping
+    pub wraps_x: bool,
+}
+
+impl Body {
+    /// each has the others layer in its mask
+    fn collides(&self, other: &Body) -> bool {
+        self.mask & other.layer != 0 && other.mask & self.layer != 0
+    }
+}
+
+/// uniform grid over the screen, colliders are dropped into every cell
+/// their box touches and only colliders sharing a cell are paired up
+pub struct Broadphase {
+    cell_size: f32,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    bodies: Vec<Body>,
+}
+
+impl Broadphase {
+    pub fn new(width: f32, height: f32, cell_size: f32) -> Self {
+        let columns = (width / cell_size).ceil().max(1.) as usize;
+        let rows = (height / cell_size).ceil().max(1.) as usize;
+
+        Self {
+            cell_size,
+            columns,
+            rows,
+            cells: vec![vec![]; columns * rows],
+            bodies: vec![],
+        }
+    }
+
+    /// empty the grid, keeps its memory for the next step
+    pub fn clear(&mut self) {
+        for cell in self.cells.iter_mut() {
+            cell.clear();
+        }
+        self.bodies.clear();
+    }
+
+    /// grid cell for a screen position, off screen clamps to the edge cells
+    fn cell_coords(&self, x: f32, y: f32) -> (usize, usize) {
+        let cx = (x / self.cell_size).floor().max(0.) as usize;
+        let cy = (y / self.cell_size).floor().max(0.) as usize;
+        (cx.min(self.columns - 1), cy.min(self.rows - 1))
+    }
+
+    /// add a collider covering box_area
+    pub fn insert(&mut self, body: Body, box_area: &BoxArea) {
+        let min = box_area.get_position();
+        self.insert_bounds(body, min, min + box_area.get_size());
+    }
+
+    /// add a collider covering the rectangle min to max,
+    /// along with its ghosts past the screen edges
+    pub fn insert_bounds(&mut self, body: Body, min: Vector2f, max: Vector2f) {
+        let index = self.bodies.len();
+        self.bodies.push(body);
+
+        for offset in ghost_offsets(min, max, body.wraps_x).iter() {
+            let (min_x, min_y) = self.cell_coords(min.x + offset.x, min.y + offset.y);
+            let (max_x, max_y) = self.cell_coords(max.x + offset.x, max.y + offset.y);
+
+            for cy in min_y..=max_y {
+                for cx in min_x..=max_x {
+                    let cell = &mut self.cells[cy * self.columns + cx];
+                    if !cell.contains(&index) {
+                        cell.push(index);
+                    }
+                }
+            }
+        }
+    }
+
+    /// every pair of colliders sharing a cell whose masks let them hit,
+    /// once each, lower layer first then lower id. sorted so the order
+    /// never depends on grid layout
+    pub fn pairs(&self) -> Vec<(Body, Body)> {
+        let mut pairs: Vec<(Body, Body)> = vec![];
+
+        for cell in self.cells.iter() {
+            for (n, &i) in cell.iter().enumerate() {
+                for &j in cell.iter().skip(n + 1) {
+                    let a = self.bodies[i];
+                    let b = self.bodies[j];
+                    if !a.collides(&b) {
+                        continue;
+                    }
+                    pairs.push(if a < b { (a, b) } else { (b, a) });
+                }
+            }
+        }
+
+        pairs.sort();
+        pairs.dedup();
+        pairs
+    }
+}
@@ -3,10 +3,19 @@ use sfml::{graphics::*, system::*};
 //
 use crate::baseobject::BaseObject;
 use crate::boxarea::BoxArea;
+use crate::interpolate::lerp_points;
 use crate::isactive::IsActive;
+use crate::layer::{self, LayerBits};
 
-// so bullets from alien dont destroy asteroids
-#[derive(PartialEq)]
+/// pixels per second
+pub const BULLET_SPEED: f32 = 400.;
+/// half the length of a bullets body
+const HALF_LENGTH: f32 = 5.;
+/// seconds before a bullet can hit whoever fired it
+const ARM_TIME: f32 = 0.15;
+
+/// who fired a bullet
+#[derive(Copy, Clone, PartialEq)]
 pub enum ShooterType {
     PLAYER,
     ALIEN,
@@ -19,7 +28,9 @@ pub struct Bullet {
     max_life_time: f32,
     bullet_points: [Vertex; 5],
     transform_bullet_points: [Vertex; 5],
+    prev_transform_bullet_points: [Vertex; 5],
     transform_points: Vec<Vector2f>,
+    prev_position: Vector2f,
     is_debug: bool,
     mask: ShooterType,
     box_area: BoxArea,
@@ -46,7 +57,7 @@ impl Bullet {
         ];
         let draw_bv = [Vertex::default(); 5];
 
-        let acc = 400.;
+        let acc = BULLET_SPEED;
 
         let dx = ang.cos() * acc;
         let dy = ang.sin() * acc;
@@ -55,7 +66,7 @@ impl Bullet {
 
         let ba = BoxArea::new(x, y, 10., 10.);
 
-        Self {
+        let mut bullet = Self {
             base: BaseObject {
                 position: Vector2f::new(x, y),
                 velocity: Vector2f::new(dx, dy),
@@ -67,11 +78,17 @@ impl Bullet {
             max_life_time: 1.5,
             bullet_points: bullet_v,
             transform_bullet_points: draw_bv,
+            prev_transform_bullet_points: draw_bv,
             transform_points: tp,
+            prev_position: Vector2f::new(x, y),
             is_debug: false,
             box_area: ba,
             mask: mask,
-        }
+        };
+
+        bullet.update_points();
+        bullet.prev_transform_bullet_points = bullet.transform_bullet_points;
+        bullet
     }
 
     /// get owner of this current projectile
@@ -79,11 +96,34 @@ impl Bullet {
         &self.mask
     }
 
+    /// collision layer it sits on, which side fired it
+    pub fn get_layer(&self) -> LayerBits {
+        match self.mask {
+            ShooterType::PLAYER => layer::PLAYER_PROJECTILE,
+            ShooterType::ALIEN => layer::ENEMY_PROJECTILE,
+        }
+    }
+
+    /// true once it has flown clear of whoever fired it
+    pub fn is_armed(&self) -> bool {
+        self.life_timer >= ARM_TIME
+    }
+
     /// get vec of the current transform points for this ship
+    #[allow(dead_code)]
     pub fn get_tp(&self) -> &Vec<Vector2f> {
         &self.transform_points
     }
 
+    /// path the bullet covered over the last step, from the tail where it
+    /// was to the nose where it is now. hits are tested along this so a
+    /// fast bullet cant skip over something thin between steps
+    pub fn get_sweep(&self) -> (Vector2f, Vector2f) {
+        let dir = Vector2f::new(self.base.angle.cos(), self.base.angle.sin()) * HALF_LENGTH;
+        (self.prev_position - dir, self.base.position + dir)
+    }
+
+    #[allow(dead_code)]
     pub fn get_box_area(&self) -> &BoxArea {
         &self.box_area
     }
@@ -93,14 +133,15 @@ impl Bullet {
         self.base.position
     }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    /// draw bullet blended between the last two steps by alpha
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         if self.is_active() {
             if self.is_debug {
                 self.box_area.draw(window);
             }
 
             window.draw_primitives(
-                &self.transform_bullet_points,
+                &lerp_points(&self.prev_transform_bullet_points, &self.transform_bullet_points, alpha),
                 PrimitiveType::LineStrip,
                 RenderStates::default(),
             );
@@ -130,6 +171,9 @@ impl Bullet {
 
     pub fn update(&mut self, delta: f32) {
         if self.is_active() {
+            self.prev_transform_bullet_points = self.transform_bullet_points;
+            self.prev_position = self.base.position;
+
             self.base.position += self.base.velocity * delta;
 
             self.life_timer += delta;
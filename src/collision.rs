@@ -0,0 +1,304 @@
+// USE
+use sfml::system::*;
+//
+use crate::boxarea::BoxArea;
+use crate::convex::ConvexParts;
+use crate::globals::{v2_dot, v2_length, v2_normal};
+use crate::wrap::box_ghost_offsets;
+
+/// how two overlapping shapes touch
+#[derive(Copy, Clone)]
+pub struct Manifold {
+    /// unit normal pointing from shape 2 towards shape 1
+    pub normal: Vector2f,
+    /// how far the shapes overlap along the normal
+    pub depth: f32,
+    /// move shape 1 by this to separate the shapes
+    pub mtv: Vector2f,
+    /// approximate point of contact
+    pub contact: Vector2f,
+}
+
+/// what the narrow phase needs from an object, borrowed for one test
+pub struct Collider<'a> {
+    pub box_area: &'a BoxArea,
+    /// transformed outline
+    pub tp: &'a [Vector2f],
+    /// convex pieces of the outline
+    pub parts: &'a ConvexParts,
+    /// false for things that leave by the sides instead of wrapping
+    pub wraps_x: bool,
+}
+
+/// average of a shapes points
+fn centroid(points: &[Vector2f]) -> Vector2f {
+    let mut sum = Vector2f::default();
+    for p in points.iter() {
+        sum += *p;
+    }
+    sum / points.len().max(1) as f32
+}
+
+/// min and max of a shape projected onto an axis
+fn project(points: &[Vector2f], axis: Vector2f) -> (f32, f32) {
+    let mut min_value = f32::INFINITY;
+    let mut max_value = -f32::INFINITY;
+    for p in points.iter() {
+        let dot = v2_dot(*p, axis);
+        // update min/max
+        min_value = min_value.min(dot);
+        max_value = max_value.max(dot);
+    }
+    (min_value, max_value)
+}
+
+/// is a point inside a convex polygon, works for either winding
+pub fn point_in_convex(point: Vector2f, points: &[Vector2f]) -> bool {
+    let n = points.len();
+    let mut sign = 0.;
+
+    for x in 0..n {
+        let a = points[x];
+        let b = points[(x + 1) % n];
+        let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+
+        if cross == 0. {
+            continue;
+        }
+        if sign == 0. {
+            sign = cross.signum();
+        } else if cross.signum() != sign {
+            return false;
+        }
+    }
+
+    n > 2
+}
+
+// link https://www.youtube.com/watch?v=7Ik2vowGcU0&t=414s
+/// separating axis test between two convex polygons,
+/// returns how they touch or None when they dont
+pub fn sat_manifold(points_1: &[Vector2f], points_2: &[Vector2f]) -> Option<Manifold> {
+    let mut depth = f32::INFINITY;
+    let mut normal = Vector2f::default();
+
+    for shape in [points_1, points_2].iter() {
+        let n = shape.len();
+
+        for x in 0..n {
+            let y = (x + 1) % n;
+            // norm, closing points repeat the first so skip empty edges
+            let edge_normal = v2_normal(shape[x], shape[y]);
+            let len = v2_length(edge_normal);
+            if len <= f32::EPSILON {
+                continue;
+            }
+            let axis = edge_normal / len;
+
+            let (min_value1, max_value1) = project(points_1, axis);
+            let (min_value2, max_value2) = project(points_2, axis);
+
+            if !(max_value2 >= min_value1 && max_value1 >= min_value2) {
+                return None;
+            }
+
+            let overlap = max_value1.min(max_value2) - min_value1.max(min_value2);
+            if overlap < depth {
+                depth = overlap;
+                normal = axis;
+            }
+        }
+    }
+
+    if depth == f32::INFINITY {
+        return None;
+    }
+
+    // point from shape 2 to shape 1
+    let c1 = centroid(points_1);
+    let c2 = centroid(points_2);
+    if v2_dot(c1 - c2, normal) < 0. {
+        normal = -normal;
+    }
+
+    // corners buried in the other shape, else the deepest corner of shape 1
+    let mut contact = Vector2f::default();
+    let mut count = 0;
+    for p in points_1.iter().filter(|p| point_in_convex(**p, points_2)) {
+        contact += *p;
+        count += 1;
+    }
+    for p in points_2.iter().filter(|p| point_in_convex(**p, points_1)) {
+        contact += *p;
+        count += 1;
+    }
+
+    if count > 0 {
+        contact /= count as f32;
+    } else {
+        let mut deepest = c1;
+        let mut min_dot = f32::INFINITY;
+        for p in points_1.iter() {
+            let dot = v2_dot(*p, normal);
+            if dot < min_dot {
+                min_dot = dot;
+                deepest = *p;
+            }
+        }
+        contact = deepest + normal * (depth * 0.5);
+    }
+
+    Some(Manifold {
+        normal,
+        depth,
+        mtv: normal * depth,
+        contact,
+    })
+}
+
+/// how far along start -> end the segment enters a convex polygon,
+/// 0 if it starts inside, None if it misses
+fn segment_entry(start: Vector2f, end: Vector2f, points: &[Vector2f]) -> Option<f32> {
+    let c = centroid(points);
+    let d = end - start;
+    let n = points.len();
+
+    let mut t_enter: f32 = 0.;
+    let mut t_exit: f32 = 1.;
+
+    for x in 0..n {
+        let a = points[x];
+        let b = points[(x + 1) % n];
+
+        // outward facing, either winding
+        let mut normal = v2_normal(a, b);
+        if v2_length(normal) <= f32::EPSILON {
+            continue;
+        }
+        if v2_dot(c - a, normal) > 0. {
+            normal = -normal;
+        }
+
+        // distance outside this edge and how fast the segment closes on it
+        let dist = v2_dot(start - a, normal);
+        let rate = v2_dot(d, normal);
+
+        if rate == 0. {
+            if dist > 0. {
+                return None;
+            }
+            continue;
+        }
+
+        let t = -dist / rate;
+        if rate < 0. {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    Some(t_enter)
+}
+
+/// continuous test for a fast moving point, the segment is the path it
+/// covered this step. returns the first point the path touches the
+/// collider or one of its ghosts across the screen edges
+pub fn sweep(start: Vector2f, end: Vector2f, collider: &Collider) -> Option<Vector2f> {
+    let min = collider.box_area.get_position();
+    let max = min + collider.box_area.get_size();
+    let pieces = collider.parts.pieces(collider.tp);
+
+    let mut first: Option<f32> = None;
+    for offset in box_ghost_offsets(collider.box_area, collider.wraps_x).iter() {
+        // move the path instead of the ghost
+        let s = start - *offset;
+        let e = end - *offset;
+
+        // cheap reject on the paths bounds
+        if s.x.max(e.x) < min.x || s.x.min(e.x) > max.x || s.y.max(e.y) < min.y || s.y.min(e.y) > max.y {
+            continue;
+        }
+
+        for piece in pieces.iter() {
+            if let Some(t) = segment_entry(s, e, piece) {
+                if first.is_none_or(|f| t < f) {
+                    first = Some(t);
+                }
+            }
+        }
+    }
+
+    // where the bullet itself was, not the ghost
+    first.map(|t| start + (end - start) * t)
+}
+
+/// aabb collision with box2 moved by offset
+fn aabb_offset(box1: &BoxArea, box2: &BoxArea, offset: Vector2f) -> bool {
+    // x
+    let min_x1 = box1.get_position().x;
+    let max_x1 = box1.get_position().x + box1.get_size().x;
+    let min_x2 = box2.get_position().x + offset.x;
+    let max_x2 = box2.get_position().x + box2.get_size().x + offset.x;
+
+    // y
+    let min_y1 = box1.get_position().y;
+    let max_y1 = box1.get_position().y + box1.get_size().y;
+    let min_y2 = box2.get_position().y + offset.y;
+    let max_y2 = box2.get_position().y + box2.get_size().y + offset.y;
+
+    let is_overlapping = |min_a: f32, max_a: f32, min_b: f32, max_b: f32| -> bool {
+        min_b <= max_a && min_a <= max_b
+    };
+
+    // results
+    let check_a = is_overlapping(min_x1, max_x1, min_x2, max_x2);
+    let check_b = is_overlapping(min_y1, max_y1, min_y2, max_y2);
+
+    check_a && check_b
+}
+
+/// where to try shape 2 so overlaps across the screen edges are found,
+/// at its own ghosts and opposite shape 1s ghosts
+fn wrap_offsets(collider_1: &Collider, collider_2: &Collider) -> Vec<Vector2f> {
+    let mut offsets = box_ghost_offsets(collider_2.box_area, collider_2.wraps_x);
+    for o in box_ghost_offsets(collider_1.box_area, collider_1.wraps_x).iter().skip(1) {
+        if !offsets.contains(&-*o) {
+            offsets.push(-*o);
+        }
+    }
+    offsets
+}
+
+/// aabb first as a cheap reject, then sat between every pair of convex
+/// pieces, the deepest overlap says how the shapes touch. shape 2 is
+/// tried at each of its wrapped positions, the contact is on shape 1s side
+pub fn collide(collider_1: &Collider, collider_2: &Collider) -> Option<Manifold> {
+    let pieces_1 = collider_1.parts.pieces(collider_1.tp);
+    let pieces_2 = collider_2.parts.pieces(collider_2.tp);
+
+    let mut result: Option<Manifold> = None;
+    for offset in wrap_offsets(collider_1, collider_2).iter() {
+        if !aabb_offset(collider_1.box_area, collider_2.box_area, *offset) {
+            continue;
+        }
+
+        for piece_2 in pieces_2.iter() {
+            let moved: Vec<Vector2f> = piece_2.iter().map(|p| *p + *offset).collect();
+
+            for piece_1 in pieces_1.iter() {
+                if let Some(m) = sat_manifold(piece_1, &moved) {
+                    if result.is_none_or(|r| m.depth > r.depth) {
+                        result = Some(m);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
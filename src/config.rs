@@ -0,0 +1,58 @@
+// USE
+use std::env;
+//
+use crate::mode::GameMode;
+
+/// start up options read from the command line
+///
+/// `--seed <n>` start the game rng from a known seed
+/// `--record <file>` save every steps input to a replay file, one numbered
+/// file per game written at game over, on exit or when the game crashes
+/// `--replay <file>` play back a replay file instead of reading the keyboard
+/// `--mode <name>` rules to play by, `classic`, `physics` or `crossfire`
+pub struct Config {
+    pub seed: u64,
+    pub record: Option<String>,
+    pub replay: Option<String>,
+    pub mode: GameMode,
+}
+
+impl Config {
+    /// read options from the command line,
+    /// anything missing or unreadable falls back to a default
+    pub fn from_args() -> Self {
+        let mut config = Self {
+            seed: rand::random(),
+            record: None,
+            replay: None,
+            mode: GameMode::classic(),
+        };
+
+        let args: Vec<String> = env::args().skip(1).collect();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--seed" => match iter.next().map(|x| x.parse::<u64>()) {
+                    Some(Ok(seed)) => config.seed = seed,
+                    _ => eprintln!("--seed expects a number, using {}", config.seed),
+                },
+                "--record" => match iter.next() {
+                    Some(path) => config.record = Some(path.clone()),
+                    None => eprintln!("--record expects a file path"),
+                },
+                "--replay" => match iter.next() {
+                    Some(path) => config.replay = Some(path.clone()),
+                    None => eprintln!("--replay expects a file path"),
+                },
+                "--mode" => match iter.next().map(|x| GameMode::from_name(x)) {
+                    Some(Some(mode)) => config.mode = mode,
+                    _ => eprintln!("--mode expects classic, physics or crossfire, using {}", config.mode.name),
+                },
+                _ => eprintln!("unknown option: {}", arg),
+            }
+        }
+
+        config
+    }
+}
@@ -0,0 +1,195 @@
+// USE
+use sfml::{graphics::*, system::*};
+//
+use crate::globals::v2_distance_to_sq;
+
+/// outlines whose last point is within this of the first are closed line strips
+const CLOSING_DISTANCE: f32 = 0.5;
+
+/// twice the signed area of a polygon, the sign gives the winding
+fn signed_area(points: &[Vector2f]) -> f32 {
+    let n = points.len();
+    let mut area = 0.;
+    for x in 0..n {
+        let a = points[x];
+        let b = points[(x + 1) % n];
+        area += a.x * b.y - b.x * a.y;
+    }
+    area
+}
+
+/// turn at b going a -> b -> c, positive when it agrees with a positive area
+fn cross(a: Vector2f, b: Vector2f, c: Vector2f) -> f32 {
+    (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x)
+}
+
+/// is p inside or on triangle a, b, c with positive winding
+fn in_triangle(p: Vector2f, a: Vector2f, b: Vector2f, c: Vector2f) -> bool {
+    cross(a, b, p) >= 0. && cross(b, c, p) >= 0. && cross(c, a, p) >= 0.
+}
+
+/// does every corner of the polygon turn the same way
+fn is_convex(points: &[Vector2f], indices: &[usize]) -> bool {
+    let n = indices.len();
+    (0..n).all(|x| {
+        let a = points[indices[x]];
+        let b = points[indices[(x + 1) % n]];
+        let c = points[indices[(x + 2) % n]];
+        cross(a, b, c) >= -f32::EPSILON
+    })
+}
+
+/// join two polygons along an edge they share, None if they dont share one
+fn merge(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let na = a.len();
+    let nb = b.len();
+
+    for k in 0..na {
+        let p = a[k];
+        let q = a[(k + 1) % na];
+
+        for m in 0..nb {
+            // same edge walked the other way
+            if b[m] == q && b[(m + 1) % nb] == p {
+                // a from q round to p, then the rest of b back to q
+                let mut out: Vec<usize> = (0..na).map(|t| a[(k + 1 + t) % na]).collect();
+                out.extend((2..nb).map(|t| b[(m + t) % nb]));
+                return Some(out);
+            }
+        }
+    }
+
+    None
+}
+
+/// split a simple polygon into triangles by clipping ears
+fn triangulate(points: &[Vector2f]) -> Vec<Vec<usize>> {
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if signed_area(points) < 0. {
+        remaining.reverse();
+    }
+
+    let mut triangles: Vec<Vec<usize>> = vec![];
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let mut is_clipped = false;
+
+        for x in 0..n {
+            let i_prev = remaining[(x + n - 1) % n];
+            let i_cur = remaining[x];
+            let i_next = remaining[(x + 1) % n];
+
+            let a = points[i_prev];
+            let b = points[i_cur];
+            let c = points[i_next];
+
+            let turn = cross(a, b, c);
+
+            // a point on a straight line adds nothing
+            if turn.abs() <= f32::EPSILON {
+                remaining.remove(x);
+                is_clipped = true;
+                break;
+            }
+
+            // reflex corner
+            if turn < 0. {
+                continue;
+            }
+
+            let is_ear = remaining
+                .iter()
+                .filter(|i| **i != i_prev && **i != i_cur && **i != i_next)
+                .all(|i| !in_triangle(points[*i], a, b, c));
+
+            if is_ear {
+                triangles.push(vec![i_prev, i_cur, i_next]);
+                remaining.remove(x);
+                is_clipped = true;
+                break;
+            }
+        }
+
+        // self crossing outline, keep what is left as one piece
+        if !is_clipped {
+            break;
+        }
+    }
+
+    if remaining.len() >= 3 {
+        triangles.push(remaining);
+    }
+
+    triangles
+}
+
+/// an outline split into convex pieces so sat stays correct for
+/// concave shapes. pieces hold indices into the outline so they can
+/// be reused with the transformed points every step
+pub struct ConvexParts {
+    parts: Vec<Vec<usize>>,
+}
+
+impl ConvexParts {
+    /// split an outline, a closing point that repeats the first is left out
+    pub fn new(outline: &[Vector2f]) -> Self {
+        let mut n = outline.len();
+        if n > 3 && v2_distance_to_sq(outline[0], outline[n - 1]) <= CLOSING_DISTANCE * CLOSING_DISTANCE {
+            n -= 1;
+        }
+        let points = &outline[..n];
+
+        if n < 3 {
+            return Self {
+                parts: vec![(0..n).collect()],
+            };
+        }
+
+        // already convex, one piece in its own winding
+        let mut whole: Vec<usize> = (0..n).collect();
+        if signed_area(points) < 0. {
+            whole.reverse();
+        }
+        if is_convex(points, &whole) {
+            return Self { parts: vec![whole] };
+        }
+
+        // triangles, then join neighbours back up while they stay convex
+        let mut parts = triangulate(points);
+
+        let mut is_merging = true;
+        while is_merging {
+            is_merging = false;
+
+            'search: for x in 0..parts.len() {
+                for y in (x + 1)..parts.len() {
+                    if let Some(joined) = merge(&parts[x], &parts[y]) {
+                        if is_convex(points, &joined) {
+                            parts[x] = joined;
+                            parts.remove(y);
+                            is_merging = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { parts }
+    }
+
+    /// split the positions of a vertex outline
+    pub fn from_vertices(outline: &[Vertex]) -> Self {
+        let points: Vec<Vector2f> = outline.iter().map(|v| v.position).collect();
+        Self::new(&points)
+    }
+
+    /// each piece as points, taken from the transformed outline
+    pub fn pieces(&self, tp: &[Vector2f]) -> Vec<Vec<Vector2f>> {
+        self.parts
+            .iter()
+            .map(|part| part.iter().map(|i| tp[*i]).collect())
+            .collect()
+    }
+}
@@ -0,0 +1,54 @@
+// USE
+use crate::events::{EventListener, GameEvent};
+use crate::explosion::Explosion;
+use crate::globals::{new_rng, GameRng};
+use crate::isactive::IsActive;
+
+/// explosions and warps, started by game events. has its own rng so
+/// effects never change what happens in the simulation
+pub struct Effects {
+    explosions: Vec<Explosion>,
+    rng: GameRng,
+}
+
+impl Effects {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            explosions: vec![],
+            rng: new_rng(seed),
+        }
+    }
+
+    pub fn get_explosions(&self) -> &Vec<Explosion> {
+        &self.explosions
+    }
+
+    fn explode(&mut self, x: f32, y: f32) {
+        self.explosions.push(Explosion::new(x, y, &mut self.rng));
+    }
+
+    pub fn update(&mut self, delta: f32) {
+        for e in self.explosions.iter_mut() {
+            e.update(delta);
+        }
+        self.explosions.retain(|x| x.is_active());
+    }
+}
+
+impl EventListener for Effects {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::AsteroidDestroyed { pos, .. } => self.explode(pos.x, pos.y),
+            GameEvent::ShipHit { pos } => self.explode(pos.x, pos.y),
+            GameEvent::UfoDestroyed { pos, .. } => self.explode(pos.x, pos.y),
+            GameEvent::Hyperspace { from, to } => {
+                self.explosions.push(Explosion::warp(from.x, from.y, &mut self.rng));
+                self.explosions.push(Explosion::warp(to.x, to.y, &mut self.rng));
+            }
+            GameEvent::ShipRespawned { pos } => {
+                self.explosions.push(Explosion::warp(pos.x, pos.y, &mut self.rng));
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// USE
+use sfml::system::*;
+//
+use crate::asteroid::AsteroidSize;
+use crate::bullet::ShooterType;
+use crate::ufo::UfoSize;
+
+/// something that happened during a step. the simulation only emits
+/// these, scoring, sound, effects and the rest each react on their own
+#[derive(Copy, Clone)]
+pub enum GameEvent {
+    /// pos is where it was hit, by_player when the player did it
+    AsteroidDestroyed {
+        size: AsteroidSize,
+        pos: Vector2f,
+        by_player: bool,
+    },
+    /// the ship was destroyed, by a hit or a failed jump
+    ShipHit { pos: Vector2f },
+    UfoDestroyed {
+        size: UfoSize,
+        pos: Vector2f,
+        by_player: bool,
+    },
+    BulletFired { shooter: ShooterType },
+    /// a successful jump between two spots
+    Hyperspace { from: Vector2f, to: Vector2f },
+    ShipRespawned { pos: Vector2f },
+    /// the field was cleared, level is the one just finished
+    WaveCleared { level: u32 },
+}
+
+/// anything that wants to hear about game events
+pub trait EventListener {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+/// events emitted during the current step, in the order they happened
+pub struct EventBus {
+    events: Vec<GameEvent>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self { events: vec![] }
+    }
+
+    /// forget the last steps events
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    pub fn emit(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    pub fn get_events(&self) -> &Vec<GameEvent> {
+        &self.events
+    }
+
+    /// hand every event to a listener
+    pub fn dispatch(&self, listener: &mut dyn EventListener) {
+        for event in self.events.iter() {
+            listener.on_event(event);
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::f32::consts::PI;
 //
 use crate::isactive::IsActive;
 use crate::particle::Particle;
-use crate::globals::random_number;
+use crate::globals::{random_number, GameRng};
 
 
 pub struct Explosion {
@@ -27,7 +27,7 @@ impl IsActive for Explosion {
 }
 
 impl Explosion {
-    pub fn new(x:f32, y:f32) -> Self {
+    pub fn new(x:f32, y:f32, rng: &mut GameRng) -> Self {
         let mut  p = [
             Particle::new(),
             Particle::new(),
@@ -44,7 +44,7 @@ impl Explosion {
         let n = p.len() as f32;
         let slice = (PI * 2.) / n;
         for (idx, particle) in p.iter_mut().enumerate() {
-            let rng_ang: f32 = random_number(1., 3.14);
+            let rng_ang: f32 = random_number(rng, 1., 3.14);
 
             let ang = (idx as f32) + rng_ang * slice;
 
@@ -54,8 +54,8 @@ impl Explosion {
             let py = y + ang.sin() * r;
 
             // rng life and speed
-            let life: f32 = random_number(1.5, 2.5);
-            let acc: f32 = random_number(100., 200.);
+            let life: f32 = random_number(rng, 1.5, 2.5);
+            let acc: f32 = random_number(rng, 100., 200.);
 
             
             particle.init(px, py, acc, ang, life);
@@ -70,6 +70,43 @@ impl Explosion {
         }
     }
 
+    /// hyperspace effect, particles start on a ring and rush into x, y
+    pub fn warp(x: f32, y: f32, rng: &mut GameRng) -> Self {
+        let mut explosion = Self {
+            tally: 0,
+            particles: [
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+                Particle::new(),
+            ],
+            is_active: true,
+        };
+
+        let r = 30.;
+
+        let n = explosion.particles.len() as f32;
+        let slice = (PI * 2.) / n;
+        for (idx, particle) in explosion.particles.iter_mut().enumerate() {
+            let ang = idx as f32 * slice;
+
+            let px = x + ang.cos() * r;
+            let py = y + ang.sin() * r;
+
+            // reach the centre just as they die
+            let life: f32 = random_number(rng, 0.2, 0.35);
+            let acc = r / life;
+
+            particle.init(px, py, acc, ang + PI, life);
+        }
+
+        explosion
+    }
+
     // pub fn init(&mut self, x: f32, y: f32) {
     //     //let mut angle = 0.0;
     //     self.position.x = x;
@@ -96,10 +133,10 @@ impl Explosion {
     //     }
     // }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         if self.is_active {
-            for p in self.particles.iter_mut() {
-                p.draw(window);
+            for p in self.particles.iter() {
+                p.draw(window, alpha);
             }
         }
     }
@@ -0,0 +1,93 @@
+// USE
+use sfml::graphics::*;
+
+/// glyphs are drawn on a 4 x 6 grid, top left is 0, 0
+const GLYPH_WIDTH: f32 = 4.;
+const GLYPH_HEIGHT: f32 = 6.;
+/// gap between glyphs in grid units
+const GLYPH_GAP: f32 = 2.;
+
+type Stroke = &'static [(f32, f32)];
+
+/// line strips that make up a glyph, unknown characters are blank
+fn glyph(c: char) -> &'static [Stroke] {
+    match c.to_ascii_uppercase() {
+        'A' => &[&[(0., 6.), (0., 2.), (2., 0.), (4., 2.), (4., 6.)], &[(0., 3.), (4., 3.)]],
+        'B' => &[
+            &[(0., 0.), (0., 6.), (3., 6.), (4., 5.), (4., 4.), (3., 3.), (0., 3.)],
+            &[(0., 0.), (3., 0.), (4., 1.), (4., 2.), (3., 3.)],
+        ],
+        'C' => &[&[(4., 0.), (0., 0.), (0., 6.), (4., 6.)]],
+        'D' => &[&[(0., 0.), (0., 6.), (2., 6.), (4., 4.), (4., 2.), (2., 0.), (0., 0.)]],
+        'E' => &[&[(4., 0.), (0., 0.), (0., 6.), (4., 6.)], &[(0., 3.), (3., 3.)]],
+        'F' => &[&[(4., 0.), (0., 0.), (0., 6.)], &[(0., 3.), (3., 3.)]],
+        'G' => &[&[(4., 1.), (4., 0.), (0., 0.), (0., 6.), (4., 6.), (4., 3.), (2., 3.)]],
+        'H' => &[&[(0., 0.), (0., 6.)], &[(4., 0.), (4., 6.)], &[(0., 3.), (4., 3.)]],
+        'I' => &[&[(0., 0.), (4., 0.)], &[(2., 0.), (2., 6.)], &[(0., 6.), (4., 6.)]],
+        'J' => &[&[(4., 0.), (4., 6.), (1., 6.), (0., 5.), (0., 4.)]],
+        'K' => &[&[(0., 0.), (0., 6.)], &[(4., 0.), (0., 3.), (4., 6.)]],
+        'L' => &[&[(0., 0.), (0., 6.), (4., 6.)]],
+        'M' => &[&[(0., 6.), (0., 0.), (2., 3.), (4., 0.), (4., 6.)]],
+        'N' => &[&[(0., 6.), (0., 0.), (4., 6.), (4., 0.)]],
+        'O' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)]],
+        'P' => &[&[(0., 6.), (0., 0.), (4., 0.), (4., 3.), (0., 3.)]],
+        'Q' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)], &[(2., 4.), (4., 6.)]],
+        'R' => &[&[(0., 6.), (0., 0.), (4., 0.), (4., 3.), (0., 3.), (4., 6.)]],
+        'S' | '5' => &[&[(4., 0.), (0., 0.), (0., 3.), (4., 3.), (4., 6.), (0., 6.)]],
+        'T' => &[&[(0., 0.), (4., 0.)], &[(2., 0.), (2., 6.)]],
+        'U' => &[&[(0., 0.), (0., 6.), (4., 6.), (4., 0.)]],
+        'V' => &[&[(0., 0.), (2., 6.), (4., 0.)]],
+        'W' => &[&[(0., 0.), (1., 6.), (2., 3.), (3., 6.), (4., 0.)]],
+        'X' => &[&[(0., 0.), (4., 6.)], &[(4., 0.), (0., 6.)]],
+        'Y' => &[&[(0., 0.), (2., 3.), (4., 0.)], &[(2., 3.), (2., 6.)]],
+        'Z' => &[&[(0., 0.), (4., 0.), (0., 6.), (4., 6.)]],
+        // slashed so it doesnt read as O
+        '0' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)], &[(4., 0.), (0., 6.)]],
+        '1' => &[&[(1., 1.), (2., 0.), (2., 6.)], &[(0., 6.), (4., 6.)]],
+        '2' => &[&[(0., 0.), (4., 0.), (4., 3.), (0., 3.), (0., 6.), (4., 6.)]],
+        '3' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.)], &[(0., 3.), (4., 3.)]],
+        '4' => &[&[(0., 0.), (0., 3.), (4., 3.)], &[(4., 0.), (4., 6.)]],
+        '6' => &[&[(4., 0.), (0., 0.), (0., 6.), (4., 6.), (4., 3.), (0., 3.)]],
+        '7' => &[&[(0., 0.), (4., 0.), (4., 6.)]],
+        '8' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)], &[(0., 3.), (4., 3.)]],
+        '9' => &[&[(4., 3.), (0., 3.), (0., 0.), (4., 0.), (4., 6.), (0., 6.)]],
+        ':' => &[&[(2., 1.), (2., 2.)], &[(2., 4.), (2., 5.)]],
+        '.' => &[&[(2., 5.), (2., 6.)]],
+        '-' => &[&[(1., 3.), (3., 3.)]],
+        '_' => &[&[(0., 6.), (4., 6.)]],
+        '/' => &[&[(0., 6.), (4., 0.)]],
+        '!' => &[&[(2., 0.), (2., 4.)], &[(2., 5.), (2., 6.)]],
+        _ => &[],
+    }
+}
+
+/// width in pixels of text drawn at size
+pub fn text_width(text: &str, size: f32) -> f32 {
+    let n = text.chars().count() as f32;
+    if n == 0. {
+        return 0.;
+    }
+
+    let unit = size / GLYPH_HEIGHT;
+    (n * (GLYPH_WIDTH + GLYPH_GAP) - GLYPH_GAP) * unit
+}
+
+/// add line segments for text with its top left at x, y,
+/// size is the glyph height in pixels
+pub fn push_text(lines: &mut Vec<Vertex>, text: &str, x: f32, y: f32, size: f32, color: Color) {
+    let unit = size / GLYPH_HEIGHT;
+    let mut cursor = x;
+
+    for c in text.chars() {
+        for stroke in glyph(c).iter() {
+            for pair in stroke.windows(2) {
+                let (ax, ay) = pair[0];
+                let (bx, by) = pair[1];
+
+                lines.push(Vertex::with_pos_color((cursor + ax * unit, y + ay * unit), color));
+                lines.push(Vertex::with_pos_color((cursor + bx * unit, y + by * unit), color));
+            }
+        }
+        cursor += (GLYPH_WIDTH + GLYPH_GAP) * unit;
+    }
+}
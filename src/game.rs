@@ -0,0 +1,304 @@
+// USE
+use rand::Rng;
+use std::path::Path;
+use sfml::{graphics::*, window::*};
+//
+use crate::config::Config;
+use crate::globals::{new_rng, GameRng, TIME_STEP};
+use crate::highscores::HighScores;
+use crate::hud::Hud;
+use crate::input::InputFrame;
+use crate::mode::GameMode;
+use crate::render::draw_world;
+use crate::replay::Replay;
+use crate::world::World;
+
+/// letters kept for a high score name
+const MAX_INITIALS: usize = 3;
+
+/// where the game is, each state has its own keys and drawing
+///
+/// TITLE -> PLAYING <-> PAUSED
+/// PLAYING -> GAMEOVER -> HIGHSCORE -> TITLE
+/// GAMEOVER -> TITLE when the score doesnt make the table or was a replay
+#[derive(Copy, Clone, PartialEq)]
+pub enum GameState {
+    TITLE,
+    PLAYING,
+    PAUSED,
+    GAMEOVER,
+    HIGHSCORE,
+}
+
+/// path with n added before its extension, `run.txt` becomes `run_1.txt`
+fn numbered_path(path: &str, n: u32) -> String {
+    let p = Path::new(path);
+    let stem = p.file_stem().and_then(|x| x.to_str()).unwrap_or("replay");
+    let name = match p.extension().and_then(|x| x.to_str()) {
+        Some(ext) => format!("{}_{}.{}", stem, n, ext),
+        None => format!("{}_{}", stem, n),
+    };
+    p.with_file_name(name).to_string_lossy().into_owned()
+}
+
+/// runs the states around the world
+pub struct Game {
+    state: GameState,
+    world: World,
+    high_scores: HighScores,
+    initials: String,
+    seed_rng: GameRng,
+    next_seed: u64,
+    time_step: f32,
+    mode: GameMode,
+    /// where recordings go, numbered per game
+    record_path: Option<String>,
+    games_recorded: u32,
+    recording: Option<Replay>,
+    playback: Option<Replay>,
+}
+
+impl Game {
+    pub fn new(config: &Config) -> Self {
+        let playback = match &config.replay {
+            Some(path) => match Replay::load(path) {
+                Ok(r) => Some(r),
+                Err(e) => {
+                    eprintln!("could not load replay {}: {}", path, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // a replay brings its own step and mode
+        let (time_step, mode) = match &playback {
+            Some(r) => (r.get_time_step(), r.get_mode()),
+            None => (TIME_STEP, config.mode),
+        };
+
+        let mut game = Self {
+            state: GameState::TITLE,
+            world: World::new(config.seed, mode),
+            high_scores: HighScores::load(),
+            initials: String::new(),
+            seed_rng: new_rng(config.seed),
+            next_seed: config.seed,
+            time_step,
+            mode,
+            record_path: config.record.clone(),
+            games_recorded: 0,
+            recording: None,
+            playback,
+        };
+
+        // replays skip the title screen
+        if let Some(seed) = game.playback.as_ref().map(|r| r.get_seed()) {
+            game.start(seed);
+        }
+
+        game
+    }
+
+    pub fn get_world(&self) -> &World {
+        &self.world
+    }
+
+    pub fn get_time_step(&self) -> f32 {
+        self.time_step
+    }
+
+    /// is the world being stepped right now
+    pub fn is_simulating(&self) -> bool {
+        let is_replay_done = self.playback.as_ref().is_some_and(|r| r.is_finished());
+
+        match self.state {
+            GameState::PLAYING | GameState::GAMEOVER => !is_replay_done,
+            _ => false,
+        }
+    }
+
+    /// new world, the first game uses the configured seed
+    fn start(&mut self, seed: u64) {
+        self.world = World::new(seed, self.mode);
+        // seed is printed so a run can be repeated with --seed
+        println!("seed: {}", self.world.get_seed());
+
+        if self.record_path.is_some() {
+            self.recording = Some(Replay::new(self.world.get_seed(), self.time_step, self.mode));
+        }
+
+        self.state = GameState::PLAYING;
+    }
+
+    /// leave the game over screen
+    fn finish(&mut self) {
+        // once a replay is over normal play takes over,
+        // a replayed score is not the players to enter
+        let was_replay = self.playback.take().is_some();
+
+        if !was_replay && self.high_scores.qualifies(self.world.get_score().get_points()) {
+            self.initials.clear();
+            self.state = GameState::HIGHSCORE;
+        } else {
+            self.state = GameState::TITLE;
+        }
+    }
+
+    // INPUTS ---
+
+    fn title_key(&mut self, code: Key) {
+        if code == Key::Return {
+            let seed = self.next_seed;
+            self.next_seed = self.seed_rng.gen();
+            self.start(seed);
+        }
+    }
+
+    fn playing_key(&mut self, code: Key) {
+        if code == Key::P {
+            self.state = GameState::PAUSED;
+        }
+    }
+
+    fn paused_key(&mut self, code: Key) {
+        if code == Key::P {
+            self.state = GameState::PLAYING;
+        }
+    }
+
+    fn game_over_key(&mut self, code: Key) {
+        if code == Key::Return {
+            self.finish();
+        }
+    }
+
+    fn high_score_key(&mut self, code: Key) {
+        match code {
+            Key::BackSpace => {
+                self.initials.pop();
+            }
+            Key::Return if !self.initials.is_empty() => {
+                self.high_scores.insert(&self.initials, self.world.get_score().get_points());
+                self.high_scores.save();
+                self.state = GameState::TITLE;
+            }
+            _ => {}
+        }
+    }
+
+    /// a key went down, handled by the current state
+    pub fn key_pressed(&mut self, code: Key) {
+        match self.state {
+            GameState::TITLE => self.title_key(code),
+            GameState::PLAYING => self.playing_key(code),
+            GameState::PAUSED => self.paused_key(code),
+            GameState::GAMEOVER => self.game_over_key(code),
+            GameState::HIGHSCORE => self.high_score_key(code),
+        }
+    }
+
+    /// typed text, only used for high score initials
+    pub fn text_entered(&mut self, c: char) {
+        if self.state == GameState::HIGHSCORE && c.is_ascii_alphabetic() && self.initials.len() < MAX_INITIALS {
+            self.initials.push(c.to_ascii_uppercase());
+        }
+    }
+
+    // UPDATE ---
+
+    /// step the world once if the state allows it, returns if a step was taken
+    pub fn step(&mut self, live_input: &InputFrame) -> bool {
+        // a replay saved mid game ends the game where it stops
+        let is_replay_done = self.playback.as_ref().is_some_and(|r| r.is_finished());
+        if self.state == GameState::PLAYING && is_replay_done {
+            self.state = GameState::GAMEOVER;
+        }
+
+        if !self.is_simulating() {
+            return false;
+        }
+
+        let input = match self.playback.as_mut() {
+            Some(r) => match r.next_frame() {
+                Some(frame) => frame,
+                None => return false,
+            },
+            None => *live_input,
+        };
+
+        if let Some(r) = self.recording.as_mut() {
+            r.record(&input);
+        }
+
+        self.world.step(&input, self.time_step);
+
+        if self.state == GameState::PLAYING && self.world.is_game_over() {
+            self.state = GameState::GAMEOVER;
+            self.save_recording();
+        }
+
+        true
+    }
+
+    /// write the game being recorded to the next numbered file,
+    /// nothing happens when there is no recording
+    fn save_recording(&mut self) {
+        let (path, r) = match (self.record_path.as_ref(), self.recording.take()) {
+            (Some(p), Some(r)) => (p, r),
+            _ => return,
+        };
+
+        self.games_recorded += 1;
+        let path = numbered_path(path, self.games_recorded);
+        match r.save(&path) {
+            Ok(_) => println!("replay saved to {}", path),
+            Err(e) => eprintln!("could not save replay {}: {}", path, e),
+        }
+    }
+
+    // RENDER ---
+
+    /// world with the score and level over it
+    fn draw_play_field(&self, window: &mut RenderWindow, hud: &Hud, alpha: f32) {
+        draw_world(&self.world, window, alpha);
+        hud.draw_score(window, self.world.get_score());
+        hud.draw_level(window, self.world.get_level());
+
+        if let Some(name) = self.world.get_achievements().get_latest() {
+            hud.draw_achievement(window, name);
+        }
+    }
+
+    pub fn draw(&self, window: &mut RenderWindow, hud: &Hud, alpha: f32) {
+        match self.state {
+            GameState::TITLE => {
+                hud.draw_title(window, &self.high_scores);
+            }
+            GameState::PLAYING => {
+                self.draw_play_field(window, hud, alpha);
+            }
+            GameState::PAUSED => {
+                self.draw_play_field(window, hud, alpha);
+                hud.draw_pause(window);
+            }
+            GameState::GAMEOVER => {
+                self.draw_play_field(window, hud, alpha);
+                hud.draw_game_over(window, self.world.get_score(), self.world.get_stats());
+            }
+            GameState::HIGHSCORE => {
+                hud.draw_high_score_entry(window, self.world.get_score(), &self.initials);
+            }
+        }
+
+        hud.draw_fps(window);
+    }
+}
+
+/// the game in progress is saved when the window closes, and when a panic
+/// unwinds through here so a crash still leaves its replay behind
+impl Drop for Game {
+    fn drop(&mut self) {
+        self.save_recording();
+    }
+}
@@ -1,22 +1,32 @@
-use rand::{thread_rng, Rng};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use sfml::system::Vector2f;
 use std::f32::consts::PI;
 // CONSTS
 
 pub const SCREEN_WIDTH: u32 = 800;
 pub const SCREEN_HEIGHT: u32 = 600;
+/// fixed simulation step, 120 hz
+pub const TIME_STEP: f32 = 1.0 / 120.0;
+/// longest frame the simulation will try to catch up on
+pub const MAX_FRAME_TIME: f32 = 0.25;
 
 
+/// game owned random number generator, same seed gives the same game
+pub type GameRng = StdRng;
+
+/// new game rng from a seed
+pub fn new_rng(seed: u64) -> GameRng {
+    StdRng::seed_from_u64(seed)
+}
+
 /// random true or false
-pub fn random_bool() -> bool {
-    let mut rng = thread_rng();
+pub fn random_bool(rng: &mut GameRng) -> bool {
     let result: bool = rng.gen_bool(1.0 / 3.0);
     result
 }
 
 /// random number between min and max
-pub fn random_number(min_v: f32, max_v: f32) -> f32 {
-    let mut rng = thread_rng();
+pub fn random_number(rng: &mut GameRng, min_v: f32, max_v: f32) -> f32 {
 
     let a = min_v.min(max_v);
     let b = min_v.max(max_v);
@@ -82,6 +92,7 @@ pub fn v2_distance_to_sq(a: Vector2f, b: Vector2f)->f32{
     v2_length_sq(c)
 }
 
+#[allow(dead_code)]
 /// get angle between two vectors
 pub fn v2_angle_to_point(a: Vector2f, b: Vector2f)->f32{
     // using sin-1
@@ -0,0 +1,74 @@
+// USE
+use std::fs;
+
+/// file the table is kept in between runs
+const HIGH_SCORE_PATH: &str = "highscores.txt";
+/// entries kept in the table
+const MAX_ENTRIES: usize = 5;
+
+/// best scores with the players initials, highest first
+pub struct HighScores {
+    entries: Vec<(String, u32)>,
+}
+
+impl HighScores {
+    /// load the saved table, a missing or broken file gives an empty table
+    pub fn load() -> Self {
+        let mut entries: Vec<(String, u32)> = vec![];
+
+        if let Ok(text) = fs::read_to_string(HIGH_SCORE_PATH) {
+            for line in text.lines() {
+                let mut parts = line.split_whitespace();
+                let name = parts.next();
+                let points = parts.next().and_then(|x| x.parse::<u32>().ok());
+
+                if let (Some(n), Some(p)) = (name, points) {
+                    entries.push((String::from(n), p));
+                }
+            }
+        }
+
+        let mut high_scores = Self { entries };
+        high_scores.sort();
+        high_scores
+    }
+
+    /// write the table back to disk
+    pub fn save(&self) {
+        let text: String = self
+            .entries
+            .iter()
+            .map(|(name, points)| format!("{} {}\n", name, points))
+            .collect();
+
+        if let Err(e) = fs::write(HIGH_SCORE_PATH, text) {
+            eprintln!("could not save high scores: {}", e);
+        }
+    }
+
+    fn sort(&mut self) {
+        self.entries.sort_by_key(|x| std::cmp::Reverse(x.1));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    pub fn get_entries(&self) -> &Vec<(String, u32)> {
+        &self.entries
+    }
+
+    /// would this score make it onto the table
+    pub fn qualifies(&self, points: u32) -> bool {
+        if points == 0 {
+            return false;
+        }
+
+        match self.entries.last() {
+            Some(last) if self.entries.len() >= MAX_ENTRIES => points > last.1,
+            _ => true,
+        }
+    }
+
+    pub fn insert(&mut self, name: &str, points: u32) {
+        self.entries.push((String::from(name), points));
+        self.sort();
+    }
+}
@@ -0,0 +1,143 @@
+// USE
+use sfml::{graphics::*, system::*};
+//
+use crate::font::{push_text, text_width};
+use crate::globals::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::highscores::HighScores;
+use crate::score::Score;
+use crate::stats::Stats;
+
+/// where text sits relative to its x position
+#[derive(Copy, Clone)]
+pub enum Align {
+    LEFT,
+    CENTER,
+    RIGHT,
+}
+
+/// heads up display, draws text over any game state
+pub struct Hud {
+    fps: f32,
+    frame_count: u32,
+    fps_time: f32,
+}
+
+impl Hud {
+    pub fn new() -> Self {
+        Self {
+            fps: 0.,
+            frame_count: 0,
+            fps_time: 0.,
+        }
+    }
+
+    /// count a drawn frame, fps is averaged every half second
+    pub fn update(&mut self, frame_time: f32) {
+        self.frame_count += 1;
+        self.fps_time += frame_time;
+
+        if self.fps_time >= 0.5 {
+            self.fps = self.frame_count as f32 / self.fps_time;
+            self.frame_count = 0;
+            self.fps_time = 0.;
+        }
+    }
+
+    /// draw text with the stroke font, size is the glyph height in pixels
+    pub fn draw_text(&self, window: &mut RenderWindow, text: &str, pos: Vector2f, size: f32, align: Align, color: Color) {
+        let left = match align {
+            Align::LEFT => pos.x,
+            Align::CENTER => pos.x - text_width(text, size) * 0.5,
+            Align::RIGHT => pos.x - text_width(text, size),
+        };
+
+        let mut lines: Vec<Vertex> = vec![];
+        push_text(&mut lines, text, left, pos.y, size, color);
+
+        if !lines.is_empty() {
+            window.draw_primitives(&lines, PrimitiveType::Lines, RenderStates::default());
+        }
+    }
+
+    /// score next to the lives, top left, with the multiplier while a combo runs
+    pub fn draw_score(&self, window: &mut RenderWindow, score: &Score) {
+        let mut text = score.get_points().to_string();
+
+        let multiplier = score.get_multiplier();
+        if multiplier > 1 {
+            text.push_str(&format!(" X{}", multiplier));
+        }
+
+        self.draw_text(window, &text, Vector2f::new(140., 16.), 18., Align::LEFT, Color::WHITE);
+    }
+
+    /// current level, top right
+    pub fn draw_level(&self, window: &mut RenderWindow, level: u32) {
+        let x = SCREEN_WIDTH as f32 - 20.;
+        let text = format!("LEVEL {}", level);
+        self.draw_text(window, &text, Vector2f::new(x, 16.), 18., Align::RIGHT, Color::WHITE);
+    }
+
+    /// newly unlocked achievement, top centre
+    pub fn draw_achievement(&self, window: &mut RenderWindow, name: &str) {
+        let x = SCREEN_WIDTH as f32 * 0.5;
+        let text = format!("ACHIEVEMENT {}", name);
+        self.draw_text(window, &text, Vector2f::new(x, 16.), 14., Align::CENTER, Color::YELLOW);
+    }
+
+    /// frames per second, bottom right
+    pub fn draw_fps(&self, window: &mut RenderWindow) {
+        let x = SCREEN_WIDTH as f32 - 10.;
+        let y = SCREEN_HEIGHT as f32 - 20.;
+        let text = format!("FPS {}", self.fps.round() as u32);
+        self.draw_text(window, &text, Vector2f::new(x, y), 10., Align::RIGHT, Color::GREEN);
+    }
+
+    /// large title with a smaller line under it, centred on screen
+    pub fn draw_banner(&self, window: &mut RenderWindow, title: &str, subtitle: &str) {
+        let x = SCREEN_WIDTH as f32 * 0.5;
+        let y = SCREEN_HEIGHT as f32 * 0.5;
+
+        self.draw_text(window, title, Vector2f::new(x, y - 40.), 36., Align::CENTER, Color::WHITE);
+        self.draw_text(window, subtitle, Vector2f::new(x, y + 16.), 14., Align::CENTER, Color::WHITE);
+    }
+
+    pub fn draw_pause(&self, window: &mut RenderWindow) {
+        self.draw_banner(window, "PAUSED", "PRESS P TO CONTINUE");
+    }
+
+    /// final score with the games stats under it
+    pub fn draw_game_over(&self, window: &mut RenderWindow, score: &Score, stats: &Stats) {
+        let subtitle = format!("FINAL SCORE {} - PRESS ENTER", score.get_points());
+        self.draw_banner(window, "GAME OVER", &subtitle);
+
+        let x = SCREEN_WIDTH as f32 * 0.5;
+        let y = SCREEN_HEIGHT as f32 * 0.5 + 60.;
+        self.draw_text(window, &stats.summary(), Vector2f::new(x, y), 10., Align::CENTER, Color::WHITE);
+    }
+
+    /// title with the high score table under it
+    pub fn draw_title(&self, window: &mut RenderWindow, high_scores: &HighScores) {
+        self.draw_banner(window, "SPACE", "PRESS ENTER TO START");
+
+        let x = SCREEN_WIDTH as f32 * 0.5;
+        let mut y = SCREEN_HEIGHT as f32 * 0.5 + 70.;
+
+        for (name, points) in high_scores.get_entries().iter() {
+            let text = format!("{:<3} {:>6}", name, points);
+            self.draw_text(window, &text, Vector2f::new(x, y), 14., Align::CENTER, Color::WHITE);
+            y += 24.;
+        }
+    }
+
+    /// new high score, initials typed so far with blanks for the rest
+    pub fn draw_high_score_entry(&self, window: &mut RenderWindow, score: &Score, initials: &str) {
+        let title = format!("NEW HIGH SCORE {}", score.get_points());
+        self.draw_banner(window, &title, "TYPE YOUR INITIALS THEN PRESS ENTER");
+
+        let x = SCREEN_WIDTH as f32 * 0.5;
+        let y = SCREEN_HEIGHT as f32 * 0.5 + 70.;
+        let text = format!("{:_<3}", initials);
+        self.draw_text(window, &text, Vector2f::new(x, y), 30., Align::CENTER, Color::WHITE);
+    }
+}
@@ -0,0 +1,61 @@
+// USE
+use sfml::window::*;
+//
+use std::collections::HashMap;
+
+/// snapshot of the players inputs for a single simulation step
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct InputFrame {
+    pub thrust: bool,
+    pub turn_left: bool,
+    pub turn_right: bool,
+    pub shoot: bool,
+    pub hyperspace: bool,
+}
+
+impl InputFrame {
+    /// build an input frame from the current key map
+    pub fn from_key_map(key_map: &HashMap<&Key, bool>) -> Self {
+        let is_down = |key: &Key| -> bool { *key_map.get(key).unwrap_or(&false) };
+
+        Self {
+            thrust: is_down(&Key::W),
+            turn_left: is_down(&Key::A),
+            turn_right: is_down(&Key::D),
+            shoot: is_down(&Key::Space),
+            hyperspace: is_down(&Key::S),
+        }
+    }
+
+    /// pack into bits for saving, one bit per input
+    pub fn to_bits(self) -> u8 {
+        let mut bits = 0;
+        if self.thrust {
+            bits |= 1;
+        }
+        if self.turn_left {
+            bits |= 1 << 1;
+        }
+        if self.turn_right {
+            bits |= 1 << 2;
+        }
+        if self.shoot {
+            bits |= 1 << 3;
+        }
+        if self.hyperspace {
+            bits |= 1 << 4;
+        }
+        bits
+    }
+
+    /// unpack from bits made with to_bits
+    pub fn from_bits(bits: u8) -> Self {
+        Self {
+            thrust: bits & 1 != 0,
+            turn_left: bits & (1 << 1) != 0,
+            turn_right: bits & (1 << 2) != 0,
+            shoot: bits & (1 << 3) != 0,
+            hyperspace: bits & (1 << 4) != 0,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// USE
+use sfml::graphics::*;
+//
+use crate::globals::v2_distance_to_sq;
+
+/// if a point moved further than this in one step it wrapped or teleported
+const SNAP_DISTANCE: f32 = 100.;
+
+/// blend previous and current transform points for drawing between two steps
+pub fn lerp_points(prev: &[Vertex], current: &[Vertex], alpha: f32) -> Vec<Vertex> {
+    let mut result = current.to_vec();
+
+    // dont smear objects across the screen when they wrap
+    let is_snapping = prev
+        .iter()
+        .zip(current.iter())
+        .any(|(a, b)| v2_distance_to_sq(a.position, b.position) > SNAP_DISTANCE * SNAP_DISTANCE);
+
+    if is_snapping || prev.len() != current.len() {
+        return result;
+    }
+
+    for (idx, v) in result.iter_mut().enumerate() {
+        v.position = prev[idx].position + (current[idx].position - prev[idx].position) * alpha;
+    }
+
+    result
+}
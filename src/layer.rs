@@ -0,0 +1,63 @@
+/// collision layers, one bit each. every collider sits on one layer and
+/// carries a mask of the layers it collides with
+pub type LayerBits = u8;
+
+pub const PLAYER: LayerBits = 1 << 0;
+pub const PLAYER_PROJECTILE: LayerBits = 1 << 1;
+pub const ENEMY: LayerBits = 1 << 2;
+pub const ENEMY_PROJECTILE: LayerBits = 1 << 3;
+/// asteroids and anything else that hurts whatever it touches
+pub const HAZARD: LayerBits = 1 << 4;
+/// collected by the ship, nothing drops them yet
+pub const PICKUP: LayerBits = 1 << 5;
+
+/// how many layers there are
+const LAYER_COUNT: usize = 6;
+
+/// slot for a single layer bit
+fn index(layer: LayerBits) -> usize {
+    layer.trailing_zeros() as usize
+}
+
+/// mask for every layer, kept the same both ways round
+#[derive(Copy, Clone, PartialEq)]
+pub struct LayerMasks {
+    masks: [LayerBits; LAYER_COUNT],
+}
+
+impl LayerMasks {
+    /// nothing collides with anything
+    pub fn none() -> Self {
+        Self {
+            masks: [0; LAYER_COUNT],
+        }
+    }
+
+    /// same masks with layers a and b colliding or not
+    pub fn with(mut self, a: LayerBits, b: LayerBits, is_hit: bool) -> Self {
+        if is_hit {
+            self.masks[index(a)] |= b;
+            self.masks[index(b)] |= a;
+        } else {
+            self.masks[index(a)] &= !b;
+            self.masks[index(b)] &= !a;
+        }
+        self
+    }
+
+    /// the original rules, enemy shots and the saucer pass through asteroids
+    /// and nobody can shoot themselves
+    pub fn classic() -> Self {
+        Self::none()
+            .with(PLAYER, ENEMY_PROJECTILE, true)
+            .with(PLAYER, HAZARD, true)
+            .with(PLAYER, PICKUP, true)
+            .with(ENEMY, PLAYER_PROJECTILE, true)
+            .with(PLAYER_PROJECTILE, HAZARD, true)
+    }
+
+    /// layers a collider on layer collides with
+    pub fn mask(&self, layer: LayerBits) -> LayerBits {
+        self.masks[index(layer)]
+    }
+}
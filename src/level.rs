@@ -0,0 +1,64 @@
+/// asteroids in the first wave
+const START_WAVE_SIZE: usize = 3;
+/// most asteroids a wave can start with
+const MAX_WAVE_SIZE: usize = 11;
+/// how much faster each level is
+const SPEED_PER_LEVEL: f32 = 0.08;
+/// fastest a wave can be compared to the first
+const MAX_SPEED_SCALE: f32 = 1.5;
+
+/// tracks the level and when the next wave is due
+pub struct LevelManager {
+    level: u32,
+    is_waiting: bool,
+    wait_time: f32,
+    max_wait_time: f32,
+}
+
+impl LevelManager {
+    pub fn new() -> Self {
+        Self {
+            level: 1,
+            is_waiting: false,
+            wait_time: 0.,
+            max_wait_time: 2.,
+        }
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    /// large asteroids in the current wave
+    pub fn wave_size(&self) -> usize {
+        (START_WAVE_SIZE + self.level as usize - 1).min(MAX_WAVE_SIZE)
+    }
+
+    /// scale for asteroid speed in the current wave
+    pub fn speed_scale(&self) -> f32 {
+        (1. + (self.level - 1) as f32 * SPEED_PER_LEVEL).min(MAX_SPEED_SCALE)
+    }
+
+    /// call once a step, returns true when the field has been clear
+    /// long enough and the next level should spawn its wave
+    pub fn update(&mut self, delta: f32, is_field_clear: bool) -> bool {
+        if !is_field_clear {
+            self.is_waiting = false;
+            return false;
+        }
+
+        if !self.is_waiting {
+            self.is_waiting = true;
+            self.wait_time = 0.;
+        }
+
+        self.wait_time += delta;
+        if self.wait_time >= self.max_wait_time {
+            self.is_waiting = false;
+            self.level += 1;
+            return true;
+        }
+
+        false
+    }
+}
@@ -1,4 +1,6 @@
 use sfml::{graphics::*, system::*};
+//
+use crate::events::{EventListener, GameEvent};
 
 struct LifePoint {
     angle: f32,
@@ -50,7 +52,7 @@ impl LifePoint {
         }
     }
 
-    fn draw(&mut self, window: &mut RenderWindow) {
+    fn draw(&self, window: &mut RenderWindow) {
         if self.is_active {
             window.draw_primitives(
                 &self.transform_points,
@@ -82,12 +84,17 @@ impl Lives {
         }
     }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
-        for t in self.total.iter_mut() {
+    pub fn draw(&self, window: &mut RenderWindow) {
+        for t in self.total.iter() {
             t.draw(window);
         }
     }
 
+    /// lives left
+    pub fn get_amount(&self) -> usize {
+        self.amount
+    }
+
     pub fn remove_life(&mut self) {
         if self.amount > 0 {
             if let Some(x) = self.total.get_mut(self.amount - 1) {
@@ -103,3 +110,11 @@ impl Lives {
         }
     }
 }
+
+impl EventListener for Lives {
+    fn on_event(&mut self, event: &GameEvent) {
+        if let GameEvent::ShipHit { .. } = event {
+            self.remove_life();
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// USE
+use crate::layer::{self, LayerMasks};
+
+/// rules that change between ways of playing, picked on the command line
+/// with `--mode <name>` and saved with replays so they play back the same
+#[derive(Copy, Clone, PartialEq)]
+pub struct GameMode {
+    pub name: &'static str,
+    /// which layers collide, hazards hitting hazards makes asteroids bounce
+    pub collisions: LayerMasks,
+}
+
+impl GameMode {
+    /// the original rules
+    pub fn classic() -> Self {
+        Self {
+            name: "classic",
+            collisions: LayerMasks::classic(),
+        }
+    }
+
+    /// classic with asteroids knocking into each other
+    pub fn physics() -> Self {
+        Self {
+            name: "physics",
+            collisions: LayerMasks::classic().with(layer::HAZARD, layer::HAZARD, true),
+        }
+    }
+
+    /// everything hits everything, alien shots break asteroids, the saucer
+    /// can crash into them and into the ship, and both can be hit by their
+    /// own shots
+    pub fn crossfire() -> Self {
+        Self {
+            name: "crossfire",
+            collisions: LayerMasks::classic()
+                .with(layer::ENEMY_PROJECTILE, layer::HAZARD, true)
+                .with(layer::ENEMY, layer::HAZARD, true)
+                .with(layer::PLAYER, layer::ENEMY, true)
+                .with(layer::PLAYER, layer::PLAYER_PROJECTILE, true)
+                .with(layer::ENEMY, layer::ENEMY_PROJECTILE, true),
+        }
+    }
+
+    /// every mode, the first is the default
+    pub fn all() -> [Self; 3] {
+        [Self::classic(), Self::physics(), Self::crossfire()]
+    }
+
+    /// mode with this name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|m| m.name == name)
+    }
+}
@@ -2,12 +2,14 @@
 use sfml::{graphics::*, system::*};
 //
 use crate::baseobject::BaseObject;
+use crate::interpolate::lerp_points;
 use crate::isactive::IsActive;
 
 pub struct Particle {
     base: BaseObject,
     points: [Vertex; 2],
     transform_points: [Vertex; 2],
+    prev_transform_points: [Vertex; 2],
     life_time: f32,
     max_life_time: f32,
 }
@@ -36,6 +38,7 @@ impl Particle {
             },
             points: p_shape,
             transform_points: [Vertex::default(); 2],
+            prev_transform_points: [Vertex::default(); 2],
             life_time: 0.,
             max_life_time: 0.,
         }
@@ -52,12 +55,16 @@ impl Particle {
         self.base.angle = ang;
         self.base.acceleration = acc;
         self.max_life_time = life;
+
+        self.update_points();
+        self.prev_transform_points = self.transform_points;
     }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    /// draw particle blended between the last two steps by alpha
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         if self.base.is_active {
             window.draw_primitives(
-                &self.transform_points,
+                &lerp_points(&self.prev_transform_points, &self.transform_points, alpha),
                 PrimitiveType::LineStrip,
                 RenderStates::default(),
             );
@@ -83,6 +90,8 @@ impl Particle {
 
     pub fn update(&mut self, delta: f32) {
         if self.base.is_active {
+            self.prev_transform_points = self.transform_points;
+
             self.life_time += delta;
 
             if self.life_time >= self.max_life_time {
@@ -0,0 +1,36 @@
+// USE
+use sfml::graphics::*;
+//
+use crate::world::World;
+
+/// draw the current world state, never changes the world.
+/// alpha is how far between the last two steps to draw (0 - 1)
+pub fn draw_world(world: &World, window: &mut RenderWindow, alpha: f32) {
+    // -> start
+    // ship
+    world.get_ship().draw(window, alpha);
+
+    // ufo
+    if let Some(ufo) = world.get_ufo() {
+        ufo.draw(window, alpha);
+    }
+
+    // asteroid
+    for a in world.get_asteroids().iter() {
+        a.draw(window, alpha);
+    }
+
+    // bullets
+    for bullet in world.get_bullets().iter() {
+        bullet.draw(window, alpha);
+    }
+
+    // explosions
+    for e in world.get_explosions().iter() {
+        e.draw(window, alpha);
+    }
+
+    // lives
+    world.get_lives().draw(window);
+    // <- end
+}
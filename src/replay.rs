@@ -0,0 +1,155 @@
+// USE
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+//
+use crate::input::InputFrame;
+use crate::mode::GameMode;
+
+const HEADER: &str = "rust_space replay 2";
+/// most steps a replay can hold, four hours at 120 steps a second
+const MAX_FRAMES: usize = 120 * 60 * 60 * 4;
+
+/// a recorded run, the seed, time step and mode plus the input for every
+/// step. feeding the frames back into a world made with the same seed
+/// and mode plays the run out exactly the same
+///
+/// saved as text:
+/// ```text
+/// rust_space replay 2
+/// seed 1234
+/// time_step 0.008333334
+/// mode classic
+/// <input bits> <repeat count>
+/// ...
+/// ```
+pub struct Replay {
+    seed: u64,
+    time_step: f32,
+    mode: GameMode,
+    frames: Vec<InputFrame>,
+    cursor: usize,
+}
+
+fn invalid(msg: String) -> Error {
+    Error::new(ErrorKind::InvalidData, msg)
+}
+
+/// read `<key> <value>` from a header line
+fn read_value<'a>(line: Option<&'a str>, key: &str) -> Result<&'a str> {
+    let line = line.ok_or_else(|| invalid(format!("missing {}", key)))?;
+    let mut parts = line.split_whitespace();
+
+    match (parts.next(), parts.next()) {
+        (Some(k), Some(v)) if k == key => Ok(v),
+        _ => Err(invalid(format!("expected {}, found '{}'", key, line))),
+    }
+}
+
+impl Replay {
+    /// new empty replay ready for recording
+    pub fn new(seed: u64, time_step: f32, mode: GameMode) -> Self {
+        Self {
+            seed,
+            time_step,
+            mode,
+            frames: vec![],
+            cursor: 0,
+        }
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn get_time_step(&self) -> f32 {
+        self.time_step
+    }
+
+    pub fn get_mode(&self) -> GameMode {
+        self.mode
+    }
+
+    /// add the input used for one simulation step
+    pub fn record(&mut self, input: &InputFrame) {
+        self.frames.push(*input);
+    }
+
+    /// input for the next step while playing back
+    pub fn next_frame(&mut self) -> Option<InputFrame> {
+        let frame = self.frames.get(self.cursor).copied();
+        if frame.is_some() {
+            self.cursor += 1;
+        }
+        frame
+    }
+
+    /// has every recorded step been played back
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.frames.len()
+    }
+
+    /// write replay to a file
+    pub fn save(&self, path: &str) -> Result<()> {
+        let mut out = format!(
+            "{}\nseed {}\ntime_step {}\nmode {}\n",
+            HEADER, self.seed, self.time_step, self.mode.name
+        );
+
+        // run length, inputs are held for many steps at a time
+        let mut iter = self.frames.iter().peekable();
+        while let Some(frame) = iter.next() {
+            let mut count = 1;
+            while iter.peek() == Some(&frame) {
+                iter.next();
+                count += 1;
+            }
+            out.push_str(&format!("{} {}\n", frame.to_bits(), count));
+        }
+
+        fs::write(path, out)
+    }
+
+    /// read a replay saved with save
+    pub fn load(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+
+        if lines.next() != Some(HEADER) {
+            return Err(invalid(format!("{} is not a replay file", path)));
+        }
+
+        let seed = read_value(lines.next(), "seed")?
+            .parse::<u64>()
+            .map_err(|e| invalid(format!("bad seed: {}", e)))?;
+        let time_step = read_value(lines.next(), "time_step")?
+            .parse::<f32>()
+            .map_err(|e| invalid(format!("bad time_step: {}", e)))?;
+        if !time_step.is_finite() || time_step <= 0. {
+            return Err(invalid(format!("bad time_step: {}", time_step)));
+        }
+        let mode_name = read_value(lines.next(), "mode")?;
+        let mode = GameMode::from_name(mode_name).ok_or_else(|| invalid(format!("unknown mode {}", mode_name)))?;
+
+        let mut replay = Self::new(seed, time_step, mode);
+
+        for line in lines.filter(|x| !x.trim().is_empty()) {
+            let mut parts = line.split_whitespace();
+            let bits = parts.next().and_then(|x| x.parse::<u8>().ok());
+            let count = parts.next().and_then(|x| x.parse::<usize>().ok());
+
+            match (bits, count) {
+                (Some(b), Some(c)) => {
+                    let frame = InputFrame::from_bits(b);
+                    let len = match replay.frames.len().checked_add(c) {
+                        Some(n) if n <= MAX_FRAMES => n,
+                        _ => return Err(invalid(format!("replay longer than {} steps", MAX_FRAMES))),
+                    };
+                    replay.frames.resize(len, frame);
+                }
+                _ => return Err(invalid(format!("bad frame line '{}'", line))),
+            }
+        }
+
+        Ok(replay)
+    }
+}
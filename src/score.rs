@@ -0,0 +1,105 @@
+// USE
+use crate::asteroid::AsteroidSize;
+use crate::events::{EventListener, GameEvent};
+use crate::ufo::UfoSize;
+
+/// points per level for clearing a wave
+const WAVE_BONUS: u32 = 100;
+/// highest combo multiplier
+const MAX_MULTIPLIER: u32 = 4;
+
+/// points for destroying an asteroid of this size,
+/// smaller is harder to hit so worth more
+pub fn asteroid_points(size: AsteroidSize) -> u32 {
+    match size {
+        AsteroidSize::LARGE => 20,
+        AsteroidSize::MEDIUM => 50,
+        AsteroidSize::SMALL => 100,
+        AsteroidSize::NONE => 0,
+    }
+}
+
+/// points for shooting down a saucer, the small one is worth far more
+pub fn ufo_points(size: UfoSize) -> u32 {
+    match size {
+        UfoSize::LARGE => 200,
+        UfoSize::SMALL => 1000,
+    }
+}
+
+/// players score with a multiplier for quick successive kills
+pub struct Score {
+    points: u32,
+    combo: u32,
+    combo_time: f32,
+    max_combo_time: f32,
+}
+
+impl Score {
+    pub fn new() -> Self {
+        Self {
+            points: 0,
+            combo: 0,
+            combo_time: 0.,
+            max_combo_time: 1.5,
+        }
+    }
+
+    pub fn get_points(&self) -> u32 {
+        self.points
+    }
+
+    /// current multiplier, 1 when no combo is running
+    pub fn get_multiplier(&self) -> u32 {
+        self.combo.clamp(1, MAX_MULTIPLIER)
+    }
+
+    /// add points for a kill, kills inside the combo window raise the multiplier
+    fn award(&mut self, base_points: u32) {
+        if self.combo_time > 0. {
+            self.combo += 1;
+        } else {
+            self.combo = 1;
+        }
+
+        self.points += base_points * self.get_multiplier();
+        self.combo_time = self.max_combo_time;
+    }
+
+    fn add_asteroid(&mut self, size: AsteroidSize) {
+        self.award(asteroid_points(size));
+    }
+
+    fn add_ufo(&mut self, size: UfoSize) {
+        self.award(ufo_points(size));
+    }
+
+    /// bonus for clearing the asteroid field, not part of a combo
+    fn add_wave_bonus(&mut self, level: u32) {
+        self.points += WAVE_BONUS * level;
+    }
+
+    /// count down the combo window
+    pub fn update(&mut self, delta: f32) {
+        if self.combo_time > 0. {
+            self.combo_time -= delta;
+
+            if self.combo_time <= 0. {
+                self.combo_time = 0.;
+                self.combo = 0;
+            }
+        }
+    }
+}
+
+impl EventListener for Score {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            // only what the player destroys counts
+            GameEvent::AsteroidDestroyed { size, by_player: true, .. } => self.add_asteroid(size),
+            GameEvent::UfoDestroyed { size, by_player: true, .. } => self.add_ufo(size),
+            GameEvent::WaveCleared { level } => self.add_wave_bonus(level),
+            _ => {}
+        }
+    }
+}
@@ -1,13 +1,25 @@
 // USE
-use sfml::{graphics::*, system::*, window::*};
+use sfml::{graphics::*, system::*};
 //
-use std::collections::HashMap;
 use std::f32::consts::PI;
 //
 use crate::baseobject::BaseObject;
 use crate::boxarea::BoxArea;
-use crate::globals::{v2_length, v2_unit, SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::collision::Collider;
+use crate::convex::ConvexParts;
+use crate::globals::{v2_length, v2_unit};
+use crate::input::InputFrame;
+use crate::interpolate::lerp_points;
 use crate::isactive::IsActive;
+use crate::layer::{self, LayerBits};
+use crate::wrap::{draw_wrapped, wrap_position};
+
+/// seconds the ship cant be hit after respawning
+const INVULNERABLE_TIME: f32 = 3.;
+/// seconds between hyperspace jumps
+const HYPERSPACE_COOLDOWN: f32 = 3.;
+/// blinks per second while invulnerable
+const BLINK_RATE: f32 = 8.;
 
 #[allow(dead_code)]
 pub struct Ship {
@@ -20,12 +32,18 @@ pub struct Ship {
     is_turning_right: bool,
     is_thrusting: bool,
     is_shooting: bool,
+    is_hyperspacing: bool,
     is_debug: bool,
+    invulnerable_time: f32,
+    hyperspace_time: f32,
     points: [Vertex; 4],
     transform_points: [Vertex; 4],
+    prev_transform_points: [Vertex; 4],
     tp: Vec<Vector2f>,
+    parts: ConvexParts,
     thruster_points: [Vertex; 4],
     transform_thruster_points: [Vertex; 4],
+    prev_transform_thruster_points: [Vertex; 4],
     box_area: BoxArea,
 }
 
@@ -63,7 +81,7 @@ impl Ship {
 
         let ba = BoxArea::new(x, y, 70., 70.);
 
-        Self {
+        let mut ship = Self {
             base: BaseObject {
                 position: Vector2f::new(x, y),
                 velocity: Vector2f::default(),
@@ -78,20 +96,35 @@ impl Ship {
             is_turning_right: false,
             is_thrusting: false,
             is_shooting: false,
+            is_hyperspacing: false,
             is_debug: false,
+            invulnerable_time: 0.,
+            hyperspace_time: 0.,
             points: ship_v,
             transform_points: draw_sv,
+            prev_transform_points: draw_sv,
             tp: tp,
+            parts: ConvexParts::from_vertices(&ship_v),
             thruster_points: thruster_v,
             transform_thruster_points: draw_tv,
+            prev_transform_thruster_points: draw_tv,
             box_area: ba,
-        }
+        };
+
+        ship.update_points();
+        ship.prev_transform_points = ship.transform_points;
+        ship
     }
 
     pub fn get_box_area(&self) -> &BoxArea {
         &self.box_area
     }
 
+    /// collision layer it sits on
+    pub fn get_layer(&self) -> LayerBits {
+        layer::PLAYER
+    }
+
     pub fn get_position(&self) -> Vector2f {
         self.base.position
     }
@@ -100,81 +133,113 @@ impl Ship {
         self.base.angle
     }
 
-    pub fn alive(&mut self){
+    /// pixels per second
+    pub fn get_velocity(&self) -> Vector2f {
+        self.base.velocity
+    }
+
+    /// bring the ship back at x, y standing still and briefly invulnerable
+    pub fn respawn(&mut self, x: f32, y: f32) {
+        self.base.position = Vector2f::new(x, y);
+        self.base.velocity = Vector2f::default();
+        self.base.angle = 0.;
         self.base.is_active = true;
+        self.invulnerable_time = INVULNERABLE_TIME;
+
+        self.box_area.set_position(self.get_position());
+        self.box_area.update();
+
+        // no blending from where it died
+        self.update_points();
+        self.prev_transform_points = self.transform_points;
+    }
+
+    /// hyperspace is pressed and off cooldown
+    pub fn wants_hyperspace(&self) -> bool {
+        self.is_active() && self.is_hyperspacing && self.hyperspace_time <= 0.
+    }
+
+    /// jump to x, y dead still and start the cooldown
+    pub fn hyperspace(&mut self, x: f32, y: f32) {
+        self.base.position = Vector2f::new(x, y);
+        self.base.velocity = Vector2f::default();
+        self.hyperspace_time = HYPERSPACE_COOLDOWN;
+
+        self.box_area.set_position(self.get_position());
+        self.box_area.update();
+
+        // no blending across the jump
+        self.update_points();
+        self.prev_transform_points = self.transform_points;
+    }
+
+    /// collisions are ignored for the ship while this is true
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable_time > 0.
     }
 
     /// get vec of the current transform points for this ship
+    #[allow(dead_code)]
     pub fn get_tp(&self) -> &Vec<Vector2f> {
         &self.tp
     }
 
+    /// outline, pieces and box for collision tests
+    pub fn get_collider(&self) -> Collider<'_> {
+        Collider {
+            box_area: &self.box_area,
+            tp: &self.tp,
+            parts: &self.parts,
+            wraps_x: true,
+        }
+    }
+
 
     pub fn is_fireing(&self) -> bool {
         self.is_shooting
     }
 
-    pub fn inputs(&mut self, key_map: &HashMap<&Key, bool>) {
-        // left
-        if let Some(x) = key_map.get(&Key::D) {
-            self.is_turning_left = *x
-        }
-        // right
-        if let Some(x) = key_map.get(&Key::A) {
-            self.is_turning_right = *x;
-        }
-        // up
-        if let Some(x) = key_map.get(&Key::W) {
-            self.is_thrusting = *x;
-        }
-        if let Some(x) = key_map.get(&Key::Space) {
-            self.is_shooting = *x;
-        }
+    pub fn inputs(&mut self, input: &InputFrame) {
+        self.is_turning_left = input.turn_left;
+        self.is_turning_right = input.turn_right;
+        self.is_thrusting = input.thrust;
+        self.is_shooting = input.shoot;
+        self.is_hyperspacing = input.hyperspace;
     }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    /// draw ship blended between the last two steps by alpha
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         // ship
         if self.is_active() {
+            // blink while invulnerable
+            if self.is_invulnerable() && (self.invulnerable_time * BLINK_RATE) as i32 % 2 == 0 {
+                return;
+            }
+
             if self.is_debug {
                 self.box_area.draw(window);
             }
 
             // ship
-            window.draw_primitives(
-                &self.transform_points,
+            draw_wrapped(
+                window,
+                &lerp_points(&self.prev_transform_points, &self.transform_points, alpha),
                 PrimitiveType::LineStrip,
-                RenderStates::default(),
+                &self.box_area,
             );
 
             // thruster
             if self.is_thrusting {
-                window.draw_primitives(
-                    &self.transform_thruster_points,
+                draw_wrapped(
+                    window,
+                    &lerp_points(&self.prev_transform_thruster_points, &self.transform_thruster_points, alpha),
                     PrimitiveType::LineStrip,
-                    RenderStates::default(),
+                    &self.box_area,
                 );
             }
         }
     }
 
-    fn screen_wrap(&mut self, width: f32, height: f32, padding: f32) {
-        // screen wrap
-        let screen_edge = 0.;
-
-        if self.base.position.x > width + padding {
-            self.base.position.x = screen_edge - padding;
-        }
-        if self.base.position.x < screen_edge - padding {
-            self.base.position.x = width + padding;
-        }
-        if self.base.position.y > height + padding {
-            self.base.position.y = screen_edge - padding;
-        }
-        if self.base.position.y < screen_edge - padding {
-            self.base.position.y = height + padding;
-        }
-    }
-
     fn update_points(&mut self) {
         // rotation matrix
         // [ cos - sin ]
@@ -216,6 +281,17 @@ impl Ship {
     pub fn update(&mut self, delta: f32) {
         // angle
         if self.is_active() {
+            self.prev_transform_points = self.transform_points;
+            self.prev_transform_thruster_points = self.transform_thruster_points;
+
+            if self.hyperspace_time > 0. {
+                self.hyperspace_time = (self.hyperspace_time - delta).max(0.);
+            }
+
+            if self.invulnerable_time > 0. {
+                self.invulnerable_time = (self.invulnerable_time - delta).max(0.);
+            }
+
             if self.base.angle < 0. {
                 self.base.angle += PI * 2.;
             }
@@ -224,11 +300,11 @@ impl Ship {
             }
 
             // direction
-            if self.is_turning_left {
+            if self.is_turning_right {
                 self.base.angle += self.rotation_speed * delta;
             }
 
-            if self.is_turning_right {
+            if self.is_turning_left {
                 self.base.angle -= self.rotation_speed * delta;
             }
 
@@ -251,12 +327,12 @@ impl Ship {
 
             // p=v*t
             self.base.position += self.base.velocity * delta;
+            self.base.position = wrap_position(self.base.position);
 
             // box collider
             self.box_area.set_position(self.get_position());
             self.box_area.update();
 
-            self.screen_wrap(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32, 20.);
             self.update_points();
         }
     }
@@ -0,0 +1,47 @@
+// USE
+use crate::bullet::ShooterType;
+use crate::events::{EventListener, GameEvent};
+
+/// running totals for one game, shown when it ends
+pub struct Stats {
+    shots_fired: u32,
+    asteroids_destroyed: u32,
+    ufos_destroyed: u32,
+    ships_lost: u32,
+    jumps: u32,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self {
+            shots_fired: 0,
+            asteroids_destroyed: 0,
+            ufos_destroyed: 0,
+            ships_lost: 0,
+            jumps: 0,
+        }
+    }
+
+    /// one line summary for the game over screen
+    pub fn summary(&self) -> String {
+        format!(
+            "SHOTS {} ROCKS {} UFOS {} JUMPS {} SHIPS LOST {}",
+            self.shots_fired, self.asteroids_destroyed, self.ufos_destroyed, self.jumps, self.ships_lost
+        )
+    }
+}
+
+impl EventListener for Stats {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::BulletFired {
+                shooter: ShooterType::PLAYER,
+            } => self.shots_fired += 1,
+            GameEvent::AsteroidDestroyed { by_player: true, .. } => self.asteroids_destroyed += 1,
+            GameEvent::UfoDestroyed { by_player: true, .. } => self.ufos_destroyed += 1,
+            GameEvent::ShipHit { .. } => self.ships_lost += 1,
+            GameEvent::Hyperspace { .. } => self.jumps += 1,
+            _ => {}
+        }
+    }
+}
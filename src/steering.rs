@@ -0,0 +1,110 @@
+// USE
+use sfml::system::*;
+//
+use crate::boxarea::BoxArea;
+use crate::globals::{random_number, v2_length, GameRng};
+use crate::wrap::wrap_delta_axes;
+
+/// something to steer around, a circle around a box
+#[derive(Copy, Clone)]
+pub struct Obstacle {
+    pub position: Vector2f,
+    pub radius: f32,
+}
+
+impl Obstacle {
+    /// circle that just covers a box
+    pub fn from_box(box_area: &BoxArea) -> Self {
+        let size = box_area.get_size();
+        Self {
+            position: box_area.get_position() + size * 0.5,
+            radius: size.x.max(size.y) * 0.5,
+        }
+    }
+}
+
+/// how much each behaviour counts when they are added together
+#[derive(Copy, Clone)]
+pub struct SteeringWeights {
+    pub seek: f32,
+    pub flee: f32,
+    pub avoid: f32,
+    pub wander: f32,
+}
+
+/// v scaled to length, zero stays zero
+fn with_length(v: Vector2f, length: f32) -> Vector2f {
+    let len = v2_length(v);
+    if len <= f32::EPSILON {
+        return Vector2f::default();
+    }
+    v * (length / len)
+}
+
+/// v shortened to max_length if it is longer
+pub fn truncate(v: Vector2f, max_length: f32) -> Vector2f {
+    if v2_length(v) > max_length {
+        return with_length(v, max_length);
+    }
+    v
+}
+
+/// head straight for target, across the screen edges when shorter.
+/// without wraps_x it only goes across the top and bottom
+pub fn seek(position: Vector2f, target: Vector2f, speed: f32, wraps_x: bool) -> Vector2f {
+    with_length(wrap_delta_axes(target, position, wraps_x), speed)
+}
+
+/// head away from target while it is within radius, harder the closer it is
+pub fn flee(position: Vector2f, target: Vector2f, speed: f32, radius: f32, wraps_x: bool) -> Vector2f {
+    let away = wrap_delta_axes(position, target, wraps_x);
+    let dist = v2_length(away);
+    if dist >= radius {
+        return Vector2f::default();
+    }
+    with_length(away, speed * (1. - dist / radius))
+}
+
+/// push away from obstacles near the path ahead, closer ones push harder
+pub fn avoid(
+    position: Vector2f,
+    velocity: Vector2f,
+    obstacles: &[Obstacle],
+    speed: f32,
+    look_ahead: f32,
+    wraps_x: bool,
+) -> Vector2f {
+    let ahead = position + with_length(velocity, look_ahead);
+    let mut push = Vector2f::default();
+
+    for o in obstacles.iter() {
+        // nearest of where it is and where it is going
+        let near_now = wrap_delta_axes(position, o.position, wraps_x);
+        let near_ahead = wrap_delta_axes(ahead, o.position, wraps_x);
+        let away = if v2_length(near_ahead) < v2_length(near_now) { near_ahead } else { near_now };
+
+        let clearance = o.radius + look_ahead * 0.5;
+        let dist = v2_length(away);
+        if dist < clearance {
+            push += with_length(away, speed * (1. - dist / clearance));
+        }
+    }
+
+    push
+}
+
+/// drift along a heading that turns a little at random every call,
+/// wander_angle carries the heading between calls
+pub fn wander(rng: &mut GameRng, wander_angle: &mut f32, speed: f32, turn: f32) -> Vector2f {
+    *wander_angle += random_number(rng, -turn, turn);
+    Vector2f::new(wander_angle.cos(), wander_angle.sin()) * speed
+}
+
+/// move velocity towards desired, changing it by no more than max_change
+pub fn steer(velocity: Vector2f, desired: Vector2f, max_change: f32) -> Vector2f {
+    let change = desired - velocity;
+    if v2_length(change) <= max_change {
+        return desired;
+    }
+    velocity + with_length(change, max_change)
+}
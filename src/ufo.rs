@@ -1,20 +1,108 @@
 use crate::baseobject::BaseObject;
 use crate::boxarea::BoxArea;
-use crate::globals::{random_number, SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::collision::Collider;
+use crate::convex::ConvexParts;
+use crate::globals::GameRng;
+use crate::interpolate::lerp_points;
 use crate::isactive::IsActive;
+use crate::layer::{self, LayerBits};
+use crate::steering::*;
+use crate::wrap::{draw_wrapped_vertically, screen_size, wrap_position};
 use sfml::{graphics::*, system::*};
 use std::f32::consts::PI;
 
+/// how far past the far edge a ufo goes before it is gone
+const EXIT_MARGIN: f32 = 20.;
+/// it leaves by the sides instead of wrapping, so never looks across them
+const WRAPS_X: bool = false;
+/// closer than this to the ship and the ufo backs off
+const FLEE_RADIUS: f32 = 150.;
+/// how far ahead it looks for asteroids
+const LOOK_AHEAD: f32 = 60.;
+/// most the wander heading turns in a second, radians
+const WANDER_RATE: f32 = 6.;
+/// most the velocity can change in a second, pixels per second
+const MAX_STEER: f32 = 200.;
+/// share of its speed always kept heading across the screen
+const MIN_FORWARD: f32 = 0.3;
+
+/// saucer types
+#[derive(Copy, Clone, PartialEq)]
+pub enum UfoSize {
+    /// slow, big and a poor shot
+    LARGE,
+    /// quick, hard to hit and accurate
+    SMALL,
+}
+
+impl UfoSize {
+    /// size of the outline compared to the large saucer
+    fn scale(&self) -> f32 {
+        match self {
+            UfoSize::LARGE => 1.,
+            UfoSize::SMALL => 0.6,
+        }
+    }
+
+    /// pixels per second
+    fn speed(&self) -> f32 {
+        match self {
+            UfoSize::LARGE => 100.,
+            UfoSize::SMALL => 150.,
+        }
+    }
+
+    /// seconds between shots
+    fn shoot_time(&self) -> f32 {
+        match self {
+            UfoSize::LARGE => 1.5,
+            UfoSize::SMALL => 1.,
+        }
+    }
+
+    /// the large saucer drifts about, the small one hunts the ship
+    fn weights(&self) -> SteeringWeights {
+        match self {
+            UfoSize::LARGE => SteeringWeights {
+                seek: 0.2,
+                flee: 0.6,
+                avoid: 1.5,
+                wander: 0.6,
+            },
+            UfoSize::SMALL => SteeringWeights {
+                seek: 0.7,
+                flee: 1.,
+                avoid: 1.5,
+                wander: 0.3,
+            },
+        }
+    }
+
+    /// how well it aims on level 1, 0 - 1
+    fn accuracy(&self) -> f32 {
+        match self {
+            UfoSize::LARGE => 0.2,
+            UfoSize::SMALL => 0.8,
+        }
+    }
+}
+
+/// flying saucer, crosses the screen from one side and leaves by the other
 pub struct Ufo {
     base: BaseObject,
+    ufo_size: UfoSize,
+    /// 1 heading right, -1 heading left
+    heading: f32,
     shoot_time: f32,
     max_shoot_time: f32,
-    turn_time: f32,
-    max_turn_time: f32,
+    /// where wander is heading, carried between steps
+    wander_angle: f32,
     is_shooting: bool,
     points: [Vertex; 7],
     transform_points: [Vertex; 7],
+    prev_transform_points: [Vertex; 7],
     tp: Vec<Vector2f>,
+    parts: ConvexParts,
     box_area: BoxArea,
     is_debug: bool,
 }
@@ -30,8 +118,10 @@ impl IsActive for Ufo {
 }
 
 impl Ufo {
-    pub fn new(x: f32, y: f32, acc: f32) -> Self {
-        let p = [
+    /// new ufo at x, y flying right when heading is positive, left otherwise
+    pub fn new(x: f32, y: f32, heading: f32, size_type: UfoSize) -> Self {
+        let scale = size_type.scale();
+        let mut p = [
             Vertex::with_pos((5., -5.)),
             Vertex::with_pos((16., 0.)),
             Vertex::with_pos((5., 5.)),
@@ -40,82 +130,100 @@ impl Ufo {
             Vertex::with_pos((-5., -5.)),
             Vertex::with_pos((5., -5.)),
         ];
+        for v in p.iter_mut() {
+            v.position *= scale;
+        }
 
         let ang: f32 = 0.0;
-        let dx = ang.cos() * acc;
-        let dy = ang.sin() * acc;
+        let acc = size_type.speed();
+        let heading = if heading < 0. { -1. } else { 1. };
 
-        let ba = BoxArea::new(x, y, 70., 70.);
+        let ba = BoxArea::new(x, y, 70. * scale, 70. * scale);
 
-        Self {
+        let mut ufo = Self {
             base: BaseObject {
                 position: Vector2f::new(x, y),
-                velocity: Vector2f::new(dx, dy),
+                velocity: Vector2f::new(heading * acc, 0.),
                 acceleration: acc,
                 angle: ang,
                 is_active: true,
             },
+            ufo_size: size_type,
+            heading,
             points: p,
             shoot_time: 0.0,
-            max_shoot_time: 1.5,
-            turn_time: 0.0,
-            max_turn_time: 2.5,
+            max_shoot_time: size_type.shoot_time(),
+            wander_angle: if heading > 0. { 0. } else { PI },
             is_shooting: false,
             transform_points: [Vertex::default(); 7],
+            prev_transform_points: [Vertex::default(); 7],
             tp: vec![Vector2f::default(); 7],
+            parts: ConvexParts::from_vertices(&p),
             box_area: ba,
             is_debug: false,
-        }
+        };
+
+        ufo.update_points();
+        ufo.prev_transform_points = ufo.transform_points;
+        ufo
     }
 
     pub fn get_position(&self) -> Vector2f {
         self.base.position
     }
 
+    pub fn get_ufo_size(&self) -> UfoSize {
+        self.ufo_size
+    }
+
+    /// how well it aims on level 1, 0 - 1
+    pub fn get_accuracy(&self) -> f32 {
+        self.ufo_size.accuracy()
+    }
+
     /// get vec of the current transform points for this ship
     pub fn get_tp(&self) -> &Vec<Vector2f> {
         &self.tp
     }
 
+    /// outline, pieces and box for collision tests
+    pub fn get_collider(&self) -> Collider<'_> {
+        Collider {
+            box_area: &self.box_area,
+            tp: &self.tp,
+            parts: &self.parts,
+            wraps_x: WRAPS_X,
+        }
+    }
+
     pub fn get_box_area(&self) -> &BoxArea {
         &self.box_area
     }
+
+    /// collision layer it sits on
+    pub fn get_layer(&self) -> LayerBits {
+        layer::ENEMY
+    }
+
     pub fn is_shooting(&self) -> bool {
         self.is_shooting
     }
 
-    pub fn draw(&mut self, window: &mut RenderWindow) {
+    /// draw ufo blended between the last two steps by alpha
+    pub fn draw(&self, window: &mut RenderWindow, alpha: f32) {
         if self.is_active() {
             if self.is_debug {
                 self.box_area.draw(window);
             }
 
-            window.draw_primitives(
-                &self.transform_points,
+            draw_wrapped_vertically(
+                window,
+                &lerp_points(&self.prev_transform_points, &self.transform_points, alpha),
                 PrimitiveType::LineStrip,
-                RenderStates::default(),
+                &self.box_area,
             );
         }
     }
-
-    fn screen_wrap(&mut self, width: f32, height: f32, padding: f32) {
-        // screen wrap
-        let screen_edge = 0.;
-
-        if self.base.position.x > width + padding {
-            self.base.position.x = screen_edge - padding;
-        }
-        if self.base.position.x < screen_edge - padding {
-            self.base.position.x = width + padding;
-        }
-        if self.base.position.y > height + padding {
-            self.base.position.y = screen_edge - padding;
-        }
-        if self.base.position.y < screen_edge - padding {
-            self.base.position.y = height + padding;
-        }
-    }
-    
     fn update_points(&mut self) {
         for (idx, p) in self.points.iter_mut().enumerate() {
             let x = p.position.x;
@@ -135,8 +243,37 @@ impl Ufo {
         }
     }
 
-    pub fn update(&mut self, delta: f32) {
+    /// desired velocity from all behaviours added up by weight, it always
+    /// keeps some of its speed heading across so it still leaves the screen
+    fn desired_velocity(
+        &mut self,
+        delta: f32,
+        rng: &mut GameRng,
+        ship_pos: Option<Vector2f>,
+        obstacles: &[Obstacle],
+    ) -> Vector2f {
+        let weights = self.ufo_size.weights();
+        let speed = self.base.acceleration;
+        let pos = self.base.position;
+
+        let mut desired = Vector2f::new(self.heading * speed, 0.);
+        if let Some(target) = ship_pos {
+            desired += seek(pos, target, speed, WRAPS_X) * weights.seek;
+            desired += flee(pos, target, speed, FLEE_RADIUS, WRAPS_X) * weights.flee;
+        }
+        desired += avoid(pos, self.base.velocity, obstacles, speed, LOOK_AHEAD, WRAPS_X) * weights.avoid;
+        desired += wander(rng, &mut self.wander_angle, speed, WANDER_RATE * delta) * weights.wander;
+
+        let mut desired = truncate(desired, speed);
+        desired.x = self.heading * (desired.x * self.heading).max(speed * MIN_FORWARD);
+        desired
+    }
+
+    /// ship_pos is None while there is no ship to chase or run from
+    pub fn update(&mut self, delta: f32, rng: &mut GameRng, ship_pos: Option<Vector2f>, obstacles: &[Obstacle]) {
         if self.is_active() {
+            self.prev_transform_points = self.transform_points;
+
             // try and kill player!
             self.shoot_time += delta;
             if self.shoot_time >= self.max_shoot_time {
@@ -146,25 +283,26 @@ impl Ufo {
                 self.is_shooting = false;
             }
 
-            // random new direction
-            self.turn_time += delta;
-            if self.turn_time >= self.max_turn_time {
-                // 0.0 - 6.2831
-                let new_ang = random_number(0.0, PI * 2.);
+            let desired = self.desired_velocity(delta, rng, ship_pos, obstacles);
+            self.base.velocity = steer(self.base.velocity, desired, MAX_STEER * delta);
 
-                let dx = new_ang.cos() * self.base.acceleration;
-                let dy = new_ang.sin() * self.base.acceleration;
-
-                self.base.velocity = Vector2f::new(dx, dy);
+            self.base.position += self.base.velocity * delta;
 
-                self.turn_time = 0.0;
+            // wraps top to bottom, leaves for good at the far side
+            let width = screen_size().x;
+            let is_gone = match self.heading > 0. {
+                true => self.base.position.x > width + EXIT_MARGIN,
+                false => self.base.position.x < -EXIT_MARGIN,
+            };
+            if is_gone {
+                self.kill();
+                return;
             }
+            self.base.position.y = wrap_position(self.base.position).y;
 
-            self.base.position += self.base.velocity * delta;
             self.box_area.set_position(self.get_position());
             self.box_area.update();
 
-            self.screen_wrap(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32, 20.);
             self.update_points();
         }
     }
@@ -0,0 +1,71 @@
+// USE
+use crate::globals::{random_number, GameRng};
+use crate::ufo::{Ufo, UfoSize};
+use crate::wrap::screen_size;
+
+/// seconds before the first saucer of a game
+const FIRST_WAIT: f32 = 12.;
+/// shortest and longest wait between saucers on level 1
+const MIN_WAIT: f32 = 10.;
+const MAX_WAIT: f32 = 20.;
+/// how much shorter the wait gets each level
+const WAIT_PER_LEVEL: f32 = 0.08;
+/// shortest the wait can get compared to level 1
+const MIN_WAIT_SCALE: f32 = 0.4;
+/// chance of a small saucer on level 1, and how much it grows each level
+const SMALL_CHANCE: f32 = 0.1;
+const SMALL_CHANCE_PER_LEVEL: f32 = 0.1;
+const MAX_SMALL_CHANCE: f32 = 0.8;
+/// saucers start just past the edge they come in from
+const ENTRY_MARGIN: f32 = 15.;
+
+/// sends saucers in from a side of the screen now and then,
+/// sooner and more often small ones as the levels go up
+pub struct UfoSpawner {
+    wait_time: f32,
+    next_time: f32,
+}
+
+impl UfoSpawner {
+    pub fn new() -> Self {
+        Self {
+            wait_time: 0.,
+            next_time: FIRST_WAIT,
+        }
+    }
+
+    /// scale for the wait between saucers
+    fn wait_scale(level: u32) -> f32 {
+        (1. - (level - 1) as f32 * WAIT_PER_LEVEL).max(MIN_WAIT_SCALE)
+    }
+
+    /// chance the next saucer is a small one
+    fn small_chance(level: u32) -> f32 {
+        (SMALL_CHANCE + (level - 1) as f32 * SMALL_CHANCE_PER_LEVEL).min(MAX_SMALL_CHANCE)
+    }
+
+    /// call once a step while no saucer is out, returns one when it is due
+    pub fn update(&mut self, delta: f32, level: u32, rng: &mut GameRng) -> Option<Ufo> {
+        self.wait_time += delta;
+        if self.wait_time < self.next_time {
+            return None;
+        }
+
+        let scale = Self::wait_scale(level);
+        self.wait_time = 0.;
+        self.next_time = random_number(rng, MIN_WAIT * scale, MAX_WAIT * scale);
+
+        let size_type = match random_number(rng, 0., 1.) < Self::small_chance(level) {
+            true => UfoSize::SMALL,
+            false => UfoSize::LARGE,
+        };
+
+        // in from the left heading right or the other way round
+        let screen = screen_size();
+        let heading = if random_number(rng, 0., 1.) < 0.5 { 1. } else { -1. };
+        let x = if heading > 0. { -ENTRY_MARGIN } else { screen.x + ENTRY_MARGIN };
+        let y = random_number(rng, screen.y * 0.1, screen.y * 0.9);
+
+        Some(Ufo::new(x, y, heading, size_type))
+    }
+}
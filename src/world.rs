@@ -0,0 +1,725 @@
+// USE
+use sfml::system::*;
+//
+use crate::asteroid::*;
+use crate::broadphase::*;
+use crate::bullet::*;
+use crate::collision::*;
+use crate::achievements::*;
+use crate::aim::aim;
+use crate::effects::*;
+use crate::events::*;
+use crate::explosion::*;
+use crate::globals::*;
+use crate::input::InputFrame;
+use crate::isactive::*;
+use crate::layer::{self, LayerBits};
+use crate::level::*;
+use crate::lives::*;
+use crate::mode::GameMode;
+use crate::score::*;
+use crate::ship::*;
+use crate::stats::*;
+use crate::steering::Obstacle;
+use crate::ufo::*;
+use crate::ufospawner::UfoSpawner;
+use crate::wrap::{screen_size, wrap_delta};
+
+/// closest a new wave asteroid can start to the ship
+const SAFE_SPAWN_DISTANCE: f32 = 200.;
+/// random positions tried before giving up on a safe spot
+const MAX_SPAWN_TRIES: usize = 10;
+
+/// seconds before a dead ship comes back
+const RESPAWN_DELAY: f32 = 2.;
+/// nothing may be this close to the centre for the ship to respawn
+const SAFE_RESPAWN_RADIUS: f32 = 120.;
+
+/// chance a hyperspace jump destroys the ship
+const HYPERSPACE_FAIL_CHANCE: f32 = 0.1;
+/// hyperspace never lands closer than this to a screen edge
+const HYPERSPACE_MARGIN: f32 = 50.;
+
+/// saucers aim this much better each level
+const UFO_ACCURACY_PER_LEVEL: f32 = 0.05;
+/// best a saucer ever aims, always a little off
+const MAX_UFO_ACCURACY: f32 = 0.95;
+
+/// broadphase grid cell, about the size of a large asteroid
+const BROADPHASE_CELL_SIZE: f32 = 100.;
+
+/// effects rng seed is kept apart from the simulations
+const EFFECTS_SEED_OFFSET: u64 = 0x9e37_79b9;
+
+/// a destroyed asteroid waiting to break up
+struct AsteroidSplit {
+    position: Vector2f,
+    velocity: Vector2f,
+    /// which way it was hit
+    impact: Vector2f,
+    size: AsteroidSize,
+}
+
+/// something to create once every collision this step is resolved,
+/// queued so any number of deaths in one step each get their effects
+enum SpawnRequest {
+    SPLIT(AsteroidSplit),
+}
+
+fn filter_out_inactive<T>(value: &mut Vec<T>)
+where
+    T: IsActive,
+{
+    if !value.is_empty() {
+        value.retain(|x| x.is_active());
+    }
+}
+
+/// unit vector, or along x when there is no direction to speak of
+fn direction_or_x(v: Vector2f) -> Vector2f {
+    if v2_length(v) <= f32::EPSILON {
+        return Vector2f::new(1., 0.);
+    }
+    v2_unit(v)
+}
+
+/// break an asteroid up by its sizes split rule. children keep the parents
+/// velocity, get knocked along the impact and fan out either side of it
+fn split_asteroid(rng: &mut GameRng, split: &AsteroidSplit, asteroids: &mut Vec<Asteroid>) {
+    let rule = match split.size.split_rule() {
+        Some(r) => r,
+        None => return,
+    };
+
+    let along = direction_or_x(split.impact);
+    let side = Vector2f::new(-along.y, along.x);
+    let child_radius = OutlineParams::for_size(rule.child_size).radius;
+
+    for x in 0..rule.count {
+        // -1 to 1 across the children, 0 for a lone child
+        let fan = if rule.count > 1 {
+            x as f32 / (rule.count - 1) as f32 * 2. - 1.
+        } else {
+            0.
+        };
+        let jitter = random_number(rng, 0.8, 1.2);
+
+        let velocity = split.velocity * rule.inherit
+            + side * (rule.spread_speed * fan * jitter)
+            + along * (rule.push_speed * jitter);
+        let pos = split.position + side * (child_radius * 0.5 * fan);
+
+        let mut child = Asteroid::new(
+            pos.x,
+            pos.y,
+            d_to_r(random_number(rng, 1., 360.)),
+            random_number(rng, 10., 15.),
+            3.,
+            rule.child_size,
+            rng,
+        );
+        child.set_velocity(velocity);
+        asteroids.push(child);
+    }
+}
+
+/// elastic bounce between two touching asteroids, mass grows with size.
+/// they are pushed apart, swap momentum along the contact normal and
+/// trade spin the same way
+fn bounce_asteroids(a: &mut Asteroid, b: &mut Asteroid, m: &Manifold) {
+    let mass_a = a.get_mass();
+    let mass_b = b.get_mass();
+    let total = mass_a + mass_b;
+
+    // separate, the lighter one moves further
+    a.move_by(m.mtv * (mass_b / total));
+    b.move_by(-m.mtv * (mass_a / total));
+
+    // already moving apart
+    let closing = v2_dot(a.get_velocity() - b.get_velocity(), m.normal);
+    if closing >= 0. {
+        return;
+    }
+
+    let impulse = -2. * closing / (1. / mass_a + 1. / mass_b);
+    a.set_velocity(a.get_velocity() + m.normal * (impulse / mass_a));
+    b.set_velocity(b.get_velocity() - m.normal * (impulse / mass_b));
+
+    let spin_a = a.get_rotate_speed();
+    let spin_b = b.get_rotate_speed();
+    a.set_rotate_speed(((mass_a - mass_b) * spin_a + 2. * mass_b * spin_b) / total);
+    b.set_rotate_speed(((mass_b - mass_a) * spin_b + 2. * mass_a * spin_a) / total);
+}
+
+/// all game state, stepped without any window
+pub struct World {
+    width: f32,
+    height: f32,
+    ship: Ship,
+    ufo: Option<Ufo>,
+    ufo_spawner: UfoSpawner,
+    bullets: Vec<Bullet>,
+    asteroids: Vec<Asteroid>,
+    effects: Effects,
+    lives: Lives,
+    score: Score,
+    shoot_time: f32,
+    max_shoot_time: f32,
+    events: EventBus,
+    stats: Stats,
+    achievements: Achievements,
+    rng: GameRng,
+    seed: u64,
+    level: LevelManager,
+    respawn_time: f32,
+    broadphase: Broadphase,
+    spawn_queue: Vec<SpawnRequest>,
+    mode: GameMode,
+}
+
+impl World {
+    /// the field is always the wrapped screen, see wrap::screen_size
+    pub fn new(seed: u64, mode: GameMode) -> Self {
+        let size = screen_size();
+
+        // Ship
+        let center_x = size.x * 0.5;
+        let center_y = size.y * 0.5;
+
+        let mut world = Self {
+            width: size.x,
+            height: size.y,
+            ship: Ship::new(center_x, center_y, 0.),
+            ufo: None,
+            ufo_spawner: UfoSpawner::new(),
+            bullets: Vec::new(),
+            asteroids: vec![],
+            effects: Effects::new(seed.wrapping_add(EFFECTS_SEED_OFFSET)),
+            lives: Lives::new(50., 25.),
+            score: Score::new(),
+            shoot_time: 0.0,
+            max_shoot_time: 0.5,
+            events: EventBus::new(),
+            stats: Stats::new(),
+            achievements: Achievements::new(),
+            rng: new_rng(seed),
+            seed,
+            level: LevelManager::new(),
+            respawn_time: 0.,
+            broadphase: Broadphase::new(size.x, size.y, BROADPHASE_CELL_SIZE),
+            spawn_queue: vec![],
+            mode,
+        };
+
+        world.spawn_wave();
+        world
+    }
+
+    pub fn get_ship(&self) -> &Ship {
+        &self.ship
+    }
+
+    /// the saucer, while one is out
+    pub fn get_ufo(&self) -> Option<&Ufo> {
+        self.ufo.as_ref()
+    }
+
+    pub fn get_bullets(&self) -> &Vec<Bullet> {
+        &self.bullets
+    }
+
+    pub fn get_asteroids(&self) -> &Vec<Asteroid> {
+        &self.asteroids
+    }
+
+    pub fn get_explosions(&self) -> &Vec<Explosion> {
+        self.effects.get_explosions()
+    }
+
+    pub fn get_lives(&self) -> &Lives {
+        &self.lives
+    }
+
+    pub fn get_score(&self) -> &Score {
+        &self.score
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.level.get_level()
+    }
+
+    /// no lives left
+    pub fn is_game_over(&self) -> bool {
+        self.lives.get_amount() == 0
+    }
+
+    /// seed the world rng was started from
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// everything that happened during the last step
+    pub fn get_events(&self) -> &Vec<GameEvent> {
+        self.events.get_events()
+    }
+
+    pub fn get_stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    pub fn get_achievements(&self) -> &Achievements {
+        &self.achievements
+    }
+
+    /// is nothing dangerous within the safe radius of a spot
+    fn is_spawn_area_clear(&self, pos: Vector2f) -> bool {
+        let radius_sq = SAFE_RESPAWN_RADIUS * SAFE_RESPAWN_RADIUS;
+        let is_near = |points: &Vec<Vector2f>| points.iter().any(|p| v2_length_sq(wrap_delta(*p, pos)) < radius_sq);
+
+        if self.ufo.as_ref().is_some_and(|u| u.is_active() && is_near(u.get_tp())) {
+            return false;
+        }
+
+        !self.asteroids.iter().any(|a| a.is_active() && is_near(a.get_tp()))
+    }
+
+    /// bring a dead ship back at the centre after a delay,
+    /// waiting on a clear centre and any lives left
+    fn update_respawn(&mut self, delta: f32) {
+        if self.ship.is_active() || self.is_game_over() {
+            return;
+        }
+
+        self.respawn_time += delta;
+        if self.respawn_time < RESPAWN_DELAY {
+            return;
+        }
+
+        let center = Vector2f::new(self.width * 0.5, self.height * 0.5);
+        if self.is_spawn_area_clear(center) {
+            self.ship.respawn(center.x, center.y);
+            self.events.emit(GameEvent::ShipRespawned { pos: center });
+            self.respawn_time = 0.;
+        }
+    }
+
+    /// fill the field with the current levels wave of large asteroids,
+    /// placed away from the ship so it isnt hit the moment they appear
+    fn spawn_wave(&mut self) {
+        let ship_pos = self.ship.get_position();
+        let scale = self.level.speed_scale();
+
+        for _ in 0..self.level.wave_size() {
+            let mut pos = Vector2f::default();
+
+            for _ in 0..MAX_SPAWN_TRIES {
+                pos.x = random_number(&mut self.rng, 0., self.width);
+                pos.y = random_number(&mut self.rng, 0., self.height);
+
+                if v2_length_sq(wrap_delta(pos, ship_pos)) >= SAFE_SPAWN_DISTANCE * SAFE_SPAWN_DISTANCE {
+                    break;
+                }
+            }
+
+            let new_a = Asteroid::new(
+                pos.x,
+                pos.y,
+                d_to_r(random_number(&mut self.rng, 1., 360.)),
+                random_number(&mut self.rng, 5. * scale, 15. * scale),
+                2.,
+                AsteroidSize::LARGE,
+                &mut self.rng,
+            );
+            self.asteroids.push(new_a);
+        }
+    }
+
+    /// jump the ship somewhere random, with a small chance it blows up instead
+    fn hyperspace(&mut self) {
+        let origin = self.ship.get_position();
+
+        if random_number(&mut self.rng, 0., 1.) < HYPERSPACE_FAIL_CHANCE {
+            self.ship.kill();
+            self.events.emit(GameEvent::ShipHit { pos: origin });
+            return;
+        }
+
+        let x = random_number(&mut self.rng, HYPERSPACE_MARGIN, self.width - HYPERSPACE_MARGIN);
+        let y = random_number(&mut self.rng, HYPERSPACE_MARGIN, self.height - HYPERSPACE_MARGIN);
+        self.ship.hyperspace(x, y);
+        self.events.emit(GameEvent::Hyperspace {
+            from: origin,
+            to: Vector2f::new(x, y),
+        });
+    }
+
+    /// bullet i against the ship, its own shots only once they are clear of it
+    fn shoot_ship(&mut self, i: usize) {
+        let b = &mut self.bullets[i];
+        if !b.is_active() || !self.ship.is_active() || self.ship.is_invulnerable() {
+            return;
+        }
+        if *b.get_shooter_type() == ShooterType::PLAYER && !b.is_armed() {
+            return;
+        }
+
+        let (start, end) = b.get_sweep();
+        if let Some(contact) = sweep(start, end, &self.ship.get_collider()) {
+            self.events.emit(GameEvent::ShipHit { pos: contact });
+
+            self.ship.kill();
+            b.kill();
+        }
+    }
+
+    /// ship against asteroid i
+    fn crash_ship(&mut self, i: usize) {
+        let a = &mut self.asteroids[i];
+        if !a.is_active() || !self.ship.is_active() || self.ship.is_invulnerable() {
+            return;
+        }
+
+        if let Some(m) = collide(&self.ship.get_collider(), &a.get_collider()) {
+            self.events.emit(GameEvent::AsteroidDestroyed {
+                size: a.get_asteroid_type(),
+                pos: m.contact,
+                by_player: true,
+            });
+            self.events.emit(GameEvent::ShipHit {
+                pos: self.ship.get_position(),
+            });
+
+            // hit from the ships side
+            self.spawn_queue.push(SpawnRequest::SPLIT(AsteroidSplit {
+                position: a.get_position(),
+                velocity: a.get_velocity(),
+                impact: wrap_delta(a.get_position(), self.ship.get_position()),
+                size: a.get_asteroid_type(),
+            }));
+
+            self.ship.kill();
+            a.kill();
+        }
+    }
+
+    /// ship against the ufo, both are wrecked and the player scores it
+    fn ram_ufo(&mut self) {
+        let ufo = match self.ufo.as_mut() {
+            Some(u) => u,
+            None => return,
+        };
+        if !ufo.is_active() || !self.ship.is_active() || self.ship.is_invulnerable() {
+            return;
+        }
+
+        if let Some(m) = collide(&self.ship.get_collider(), &ufo.get_collider()) {
+            self.events.emit(GameEvent::UfoDestroyed {
+                size: ufo.get_ufo_size(),
+                pos: m.contact,
+                by_player: true,
+            });
+            self.events.emit(GameEvent::ShipHit {
+                pos: self.ship.get_position(),
+            });
+
+            self.ship.kill();
+            ufo.kill();
+        }
+    }
+
+    /// bullet i against the ufo, its own shots only once they are clear of it
+    fn shoot_ufo(&mut self, i: usize) {
+        let b = &mut self.bullets[i];
+        let ufo = match self.ufo.as_mut() {
+            Some(u) => u,
+            None => return,
+        };
+        if !b.is_active() || !ufo.is_active() {
+            return;
+        }
+        let shooter = *b.get_shooter_type();
+        if shooter == ShooterType::ALIEN && !b.is_armed() {
+            return;
+        }
+
+        let (start, end) = b.get_sweep();
+        if let Some(contact) = sweep(start, end, &ufo.get_collider()) {
+            self.events.emit(GameEvent::UfoDestroyed {
+                size: ufo.get_ufo_size(),
+                pos: contact,
+                by_player: shooter == ShooterType::PLAYER,
+            });
+
+            ufo.kill();
+            b.kill();
+        }
+    }
+
+    /// ufo against asteroid i, both are wrecked and nobody scores
+    fn crash_ufo(&mut self, i: usize) {
+        let a = &mut self.asteroids[i];
+        let ufo = match self.ufo.as_mut() {
+            Some(u) => u,
+            None => return,
+        };
+        if !a.is_active() || !ufo.is_active() {
+            return;
+        }
+
+        if let Some(m) = collide(&ufo.get_collider(), &a.get_collider()) {
+            self.events.emit(GameEvent::UfoDestroyed {
+                size: ufo.get_ufo_size(),
+                pos: ufo.get_position(),
+                by_player: false,
+            });
+            self.events.emit(GameEvent::AsteroidDestroyed {
+                size: a.get_asteroid_type(),
+                pos: m.contact,
+                by_player: false,
+            });
+
+            // hit from the saucers side
+            self.spawn_queue.push(SpawnRequest::SPLIT(AsteroidSplit {
+                position: a.get_position(),
+                velocity: a.get_velocity(),
+                impact: wrap_delta(a.get_position(), ufo.get_position()),
+                size: a.get_asteroid_type(),
+            }));
+
+            ufo.kill();
+            a.kill();
+        }
+    }
+
+    /// bullet i against asteroid j
+    fn shoot_asteroid(&mut self, i: usize, j: usize) {
+        let b = &mut self.bullets[i];
+        let a = &mut self.asteroids[j];
+        if !b.is_active() || !a.is_active() {
+            return;
+        }
+
+        let (start, end) = b.get_sweep();
+        if let Some(contact) = sweep(start, end, &a.get_collider()) {
+            a.toggle_color(true);
+
+            // destroyed where the bullet struck
+            self.events.emit(GameEvent::AsteroidDestroyed {
+                size: a.get_asteroid_type(),
+                pos: contact,
+                by_player: *b.get_shooter_type() == ShooterType::PLAYER,
+            });
+            self.spawn_queue.push(SpawnRequest::SPLIT(AsteroidSplit {
+                position: a.get_position(),
+                velocity: a.get_velocity(),
+                impact: end - start,
+                size: a.get_asteroid_type(),
+            }));
+
+            // remove
+            a.kill();
+            b.kill();
+        }
+    }
+
+    /// asteroids i and j bounce off each other, i < j
+    fn bounce(&mut self, i: usize, j: usize) {
+        let (left, right) = self.asteroids.split_at_mut(j);
+        let a = &mut left[i];
+        let b = &mut right[0];
+        if !a.is_active() || !b.is_active() {
+            return;
+        }
+
+        if let Some(m) = collide(&a.get_collider(), &b.get_collider()) {
+            bounce_asteroids(a, b, &m);
+        }
+    }
+
+    /// create everything queued during collision, in the order it was queued
+    fn process_spawns(&mut self) {
+        for request in std::mem::take(&mut self.spawn_queue) {
+            match request {
+                SpawnRequest::SPLIT(split) => split_asteroid(&mut self.rng, &split, &mut self.asteroids),
+            }
+        }
+    }
+
+    /// advance the simulation by delta seconds
+    pub fn step(&mut self, input: &InputFrame, delta: f32) {
+        self.events.clear();
+
+        // INPUTS ---
+
+        self.ship.inputs(input);
+
+        if self.ship.wants_hyperspace() {
+            self.hyperspace();
+        }
+
+        // COLLISION ---
+
+        // broadphase, only colliders sharing a grid cell and whose masks
+        // let them hit are paired. masks are down to the mode
+        let masks = self.mode.collisions;
+        let body = |layer: LayerBits, id: ColliderId, wraps_x: bool| Body {
+            layer,
+            mask: masks.mask(layer),
+            id,
+            wraps_x,
+        };
+
+        self.broadphase.clear();
+        if self.ship.is_active() {
+            let wraps_x = self.ship.get_collider().wraps_x;
+            self.broadphase.insert(body(self.ship.get_layer(), ColliderId::SHIP, wraps_x), self.ship.get_box_area());
+        }
+        // the saucer leaves by the sides, no ghosts there
+        if let Some(u) = self.ufo.as_ref().filter(|u| u.is_active()) {
+            let wraps_x = u.get_collider().wraps_x;
+            self.broadphase.insert(body(u.get_layer(), ColliderId::UFO, wraps_x), u.get_box_area());
+        }
+        // bullets cover their whole path over the last step
+        for (idx, b) in self.bullets.iter().enumerate() {
+            if b.is_active() {
+                let (start, end) = b.get_sweep();
+                let min = Vector2f::new(start.x.min(end.x), start.y.min(end.y));
+                let max = Vector2f::new(start.x.max(end.x), start.y.max(end.y));
+                self.broadphase.insert_bounds(body(b.get_layer(), ColliderId::BULLET(idx), true), min, max);
+            }
+        }
+        for (idx, a) in self.asteroids.iter().enumerate() {
+            if a.is_active() {
+                let wraps_x = a.get_collider().wraps_x;
+                self.broadphase.insert(body(a.get_layer(), ColliderId::ASTEROID(idx), wraps_x), a.get_box_area());
+            }
+        }
+
+        // one pass, each pair goes to the handler for its layers.
+        // pairs come lower layer first
+        for (a, b) in self.broadphase.pairs() {
+            match (a.layer, b.layer) {
+                (layer::PLAYER, layer::PLAYER_PROJECTILE) | (layer::PLAYER, layer::ENEMY_PROJECTILE) => {
+                    self.shoot_ship(b.id.index())
+                }
+                (layer::PLAYER, layer::ENEMY) => self.ram_ufo(),
+                (layer::PLAYER, layer::HAZARD) => self.crash_ship(b.id.index()),
+                (layer::PLAYER_PROJECTILE, layer::ENEMY) => self.shoot_ufo(a.id.index()),
+                (layer::ENEMY, layer::ENEMY_PROJECTILE) => self.shoot_ufo(b.id.index()),
+                (layer::ENEMY, layer::HAZARD) => self.crash_ufo(b.id.index()),
+                (layer::PLAYER_PROJECTILE, layer::HAZARD) | (layer::ENEMY_PROJECTILE, layer::HAZARD) => {
+                    self.shoot_asteroid(a.id.index(), b.id.index())
+                }
+                (layer::HAZARD, layer::HAZARD) => self.bounce(a.id.index(), b.id.index()),
+                _ => {}
+            }
+        }
+
+        self.process_spawns();
+
+        // UPDATE ---
+        self.score.update(delta);
+        self.effects.update(delta);
+        self.achievements.update(delta);
+
+        // Ship
+        self.update_respawn(delta);
+        self.ship.update(delta);
+
+        // ship shooting
+        self.shoot_time += delta;
+        if self.ship.is_active() && self.ship.is_fireing() && self.shoot_time > self.max_shoot_time {
+            let new_b = Bullet::new(
+                self.ship.get_position().x,
+                self.ship.get_position().y,
+                self.ship.get_angle(),
+                ShooterType::PLAYER,
+            );
+
+            self.bullets.push(new_b);
+            self.events.emit(GameEvent::BulletFired {
+                shooter: ShooterType::PLAYER,
+            });
+
+            self.shoot_time = 0.;
+        }
+
+        // Ufo, gone once shot down or off the far side
+        if self.ufo.as_ref().is_some_and(|u| !u.is_active()) {
+            self.ufo = None;
+        }
+        if self.ufo.is_none() {
+            self.ufo = self.ufo_spawner.update(delta, self.level.get_level(), &mut self.rng);
+        }
+
+        if let Some(ufo) = self.ufo.as_mut() {
+            // steer around the rocks and after or away from the ship
+            let obstacles: Vec<Obstacle> = self
+                .asteroids
+                .iter()
+                .filter(|a| a.is_active())
+                .map(|a| Obstacle::from_box(a.get_box_area()))
+                .collect();
+            let ship_pos = if self.ship.is_active() { Some(self.ship.get_position()) } else { None };
+
+            ufo.update(delta, &mut self.rng, ship_pos, &obstacles);
+
+            // ufo shooting
+            if ufo.is_active() && ufo.is_shooting() && self.ship.is_active() {
+                // lead the ship, better shots on later levels
+                let accuracy = (ufo.get_accuracy() + (self.level.get_level() - 1) as f32 * UFO_ACCURACY_PER_LEVEL)
+                    .min(MAX_UFO_ACCURACY);
+                let angle = aim(
+                    &mut self.rng,
+                    ufo.get_position(),
+                    self.ship.get_position(),
+                    self.ship.get_velocity(),
+                    BULLET_SPEED,
+                    accuracy,
+                );
+
+                let new_b = Bullet::new(ufo.get_position().x, ufo.get_position().y, angle, ShooterType::ALIEN);
+
+                self.bullets.push(new_b);
+                self.events.emit(GameEvent::BulletFired {
+                    shooter: ShooterType::ALIEN,
+                });
+            }
+        }
+
+        // Bullets
+        if !self.bullets.is_empty() {
+            for bullet in self.bullets.iter_mut() {
+                bullet.update(delta);
+            }
+        }
+
+        // Asteroids
+        if !self.asteroids.is_empty() {
+            for a in self.asteroids.iter_mut() {
+                a.update(delta);
+            }
+        }
+
+        // Filter out inactive
+        filter_out_inactive(&mut self.bullets);
+        filter_out_inactive(&mut self.asteroids);
+
+        // next wave once the field has been clear for a moment
+        if self.level.update(delta, self.asteroids.is_empty()) {
+            self.events.emit(GameEvent::WaveCleared {
+                level: self.level.get_level() - 1,
+            });
+            self.spawn_wave();
+        }
+
+        // EVENTS ---
+        self.events.dispatch(&mut self.score);
+        self.events.dispatch(&mut self.lives);
+        self.events.dispatch(&mut self.effects);
+        self.events.dispatch(&mut self.stats);
+        self.events.dispatch(&mut self.achievements);
+
+        self.lives.update();
+    }
+}
@@ -0,0 +1,106 @@
+// USE
+use sfml::{graphics::*, system::*};
+//
+use crate::boxarea::BoxArea;
+use crate::globals::{SCREEN_HEIGHT, SCREEN_WIDTH};
+
+// the screen as a torus, everything leaving one edge comes back on the
+// opposite one. objects straddling an edge have ghost copies on the
+// other side which are drawn and collided with like the object itself
+
+/// screen size as floats
+pub fn screen_size() -> Vector2f {
+    Vector2f::new(SCREEN_WIDTH as f32, SCREEN_HEIGHT as f32)
+}
+
+/// bring a position back onto the screen
+pub fn wrap_position(pos: Vector2f) -> Vector2f {
+    let size = screen_size();
+    Vector2f::new(pos.x.rem_euclid(size.x), pos.y.rem_euclid(size.y))
+}
+
+/// shortest vector from b to a, going across an edge when that is shorter
+pub fn wrap_delta(a: Vector2f, b: Vector2f) -> Vector2f {
+    wrap_delta_axes(a, b, true)
+}
+
+/// wrap_delta, without wraps_x it only goes across the top and bottom
+pub fn wrap_delta_axes(a: Vector2f, b: Vector2f, wraps_x: bool) -> Vector2f {
+    let size = screen_size();
+    let mut d = a - b;
+
+    if wraps_x && d.x > size.x * 0.5 {
+        d.x -= size.x;
+    } else if wraps_x && d.x < -size.x * 0.5 {
+        d.x += size.x;
+    }
+
+    if d.y > size.y * 0.5 {
+        d.y -= size.y;
+    } else if d.y < -size.y * 0.5 {
+        d.y += size.y;
+    }
+
+    d
+}
+
+/// offsets to every copy of the rectangle min to max, the first is always
+/// the rectangle itself and the rest are its ghosts past the edges it crosses.
+/// without wraps_x it only has ghosts past the top and bottom
+pub fn ghost_offsets(min: Vector2f, max: Vector2f, wraps_x: bool) -> Vec<Vector2f> {
+    let size = screen_size();
+
+    let mut xs = vec![0.];
+    if wraps_x && min.x < 0. {
+        xs.push(size.x);
+    }
+    if wraps_x && max.x > size.x {
+        xs.push(-size.x);
+    }
+
+    let mut ys = vec![0.];
+    if min.y < 0. {
+        ys.push(size.y);
+    }
+    if max.y > size.y {
+        ys.push(-size.y);
+    }
+
+    let mut offsets = vec![];
+    for y in ys.iter() {
+        for x in xs.iter() {
+            offsets.push(Vector2f::new(*x, *y));
+        }
+    }
+    offsets
+}
+
+/// ghost offsets for a box
+pub fn box_ghost_offsets(box_area: &BoxArea, wraps_x: bool) -> Vec<Vector2f> {
+    let min = box_area.get_position();
+    ghost_offsets(min, min + box_area.get_size(), wraps_x)
+}
+
+/// draw points once at each offset
+fn draw_at(window: &mut RenderWindow, points: &[Vertex], primitive: PrimitiveType, offsets: &[Vector2f]) {
+    for offset in offsets.iter() {
+        let mut transform = Transform::IDENTITY;
+        transform.translate(offset.x, offset.y);
+
+        let states = RenderStates {
+            transform,
+            ..RenderStates::default()
+        };
+        window.draw_primitives(points, primitive, states);
+    }
+}
+
+/// draw points along with a copy for each ghost of box_area
+pub fn draw_wrapped(window: &mut RenderWindow, points: &[Vertex], primitive: PrimitiveType, box_area: &BoxArea) {
+    draw_at(window, points, primitive, &box_ghost_offsets(box_area, true));
+}
+
+/// draw_wrapped for objects that only wrap top to bottom
+pub fn draw_wrapped_vertically(window: &mut RenderWindow, points: &[Vertex], primitive: PrimitiveType, box_area: &BoxArea) {
+    draw_at(window, points, primitive, &box_ghost_offsets(box_area, false));
+}